@@ -0,0 +1,150 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use rust_aoc::{days, error::AocError, input::{InputError, InputSource}, solution::Part, verify::{self, Outcome}};
+
+const USAGE: &str = "Usage: aoc <day> [--part <1|2>] [--input <path|->] [--verify]\n       aoc --all [--part <1|2>] [--verify]\n       aoc --verify\n\nDay inputs are read from $AOC_INPUT_DIR, or resources if unset. --input - reads stdin.";
+
+struct Args {
+    /// Each (day, parts) to run, in order
+    runs: Vec<(u32, Vec<Part>)>,
+    /// Only allowed with a single day
+    input: Option<InputSource>,
+    verify: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse_args(&args) {
+        Ok(Args { runs, input, verify: false }) => {
+            for (day, parts) in runs {
+                let source = input.as_ref().map_or(Ok(InputSource::Day(day)), buffer).map_err(AocError::from);
+                if let Err(error) = source.and_then(|source| run(day, &parts, &source)) {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE
+                }
+            }
+            ExitCode::SUCCESS
+        },
+        Ok(Args { runs, verify: true, .. }) => {
+            if verify_all(&runs) { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        },
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut all = false;
+    let mut verify = false;
+    let mut input = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--verify" => verify = true,
+            "--part" => {
+                let value = it.next().ok_or("--part requires a value")?;
+                part = Some(value.parse::<Part>().map_err(|e| e.to_string())?);
+            },
+            "--input" => {
+                let value = it.next().ok_or("--input requires a path, or - for stdin")?;
+                input = Some(if value == "-" { InputSource::Stdin } else { InputSource::Path(PathBuf::from(value)) });
+            },
+            _ => {
+                let value = arg.parse::<u32>().map_err(|_| format!("Unrecognised argument {arg}"))?;
+                if days::get_solver(value).is_none() { return Err(format!("No solution for day {value}")) }
+                day = Some(value);
+            }
+        }
+    }
+
+    let parts = part.map_or_else(|| Part::all().to_vec(), |part| vec![part]);
+    let runs = match (day, all || verify) {
+        (Some(_), true) if all => return Err(String::from("Can't specify both a day and --all")),
+        (Some(day), _) => vec![(day, parts)],
+        // verifying defaults to every day
+        (None, true) => days::ALL_DAYS.map(|day| (day, parts.clone())).collect(),
+        (None, false) => return Err(String::from("No day specified")),
+    };
+    if input.is_some() && (all || verify) { return Err(String::from("--input can only be used when running a single day")) }
+    Ok(Args { runs, input, verify })
+}
+
+/// Stdin can only be read once, so it's held in memory to be shared by both parts
+fn buffer(source: &InputSource) -> Result<InputSource, InputError> {
+    match source {
+        InputSource::Stdin => Ok(InputSource::Text(source.lines()?.collect::<Vec<_>>().join("\n"))),
+        _ => Ok(source.clone()),
+    }
+}
+
+fn run(day: u32, parts: &[Part], source: &InputSource) -> Result<(), AocError> {
+    let solver = days::get_solver(day).unwrap();
+    for part in parts {
+        println!("Day {day} part {part}: {}", solver(source, *part)?);
+    }
+    Ok(())
+}
+
+/// Returns true if nothing failed or mismatched. Parts with no recorded answer are reported, but don't count as failures.
+fn verify_all(runs: &[(u32, Vec<Part>)]) -> bool {
+    // Solvers that panic are reported in the summary, don't also dump the default panic output for each one
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let (mut passed, mut mismatched, mut failed, mut unverified) = (0, 0, 0, 0);
+    for (day, parts) in runs {
+        for verification in verify::verify_day(*day, parts) {
+            println!("{verification}");
+            match verification.outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Mismatch { .. } => mismatched += 1,
+                Outcome::Fail(_) => failed += 1,
+                Outcome::Unverified(_) => unverified += 1,
+            }
+        }
+    }
+
+    let _ = std::panic::take_hook();
+    println!("{passed} passed, {mismatched} mismatched, {failed} failed, {unverified} unverified");
+    mismatched == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        parse_args(&args.split_ascii_whitespace().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn input_sources() {
+        let args = parse("5 --input in.txt").unwrap();
+        assert!(matches!(args.input, Some(InputSource::Path(path)) if path.as_os_str() == "in.txt"));
+        assert!(matches!(parse("5 --input -").unwrap().input, Some(InputSource::Stdin)));
+        assert!(parse("5").unwrap().input.is_none());
+
+        assert_eq!(parse("5 --input").err().unwrap(), "--input requires a path, or - for stdin");
+        assert_eq!(parse("--all --input in.txt").err().unwrap(), "--input can only be used when running a single day");
+    }
+
+    #[test]
+    fn verify_defaults_to_every_day() {
+        let args = parse("--verify --part 2").unwrap();
+        assert!(args.verify);
+        assert_eq!(args.runs.len(), days::ALL_DAYS.count());
+        assert!(args.runs.iter().all(|(_, parts)| *parts == [Part::Two]));
+        assert_eq!(parse("7 --verify").unwrap().runs, [(7, Part::all().to_vec())]);
+    }
+
+    #[test]
+    fn buffers_text_as_is() {
+        let source = buffer(&InputSource::from("a\nb")).unwrap();
+        assert_eq!(source.lines().unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{array, fmt::Debug, ops::Range};
+
+use num::PrimInt;
+
+/// An axis-aligned box of integer points in N dimensions, half-open along each axis
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub ranges: [Range<T>; N],
+}
+
+impl<T: PrimInt, const N: usize> Cuboid<T, N> {
+    pub fn new(ranges: [Range<T>; N]) -> Self {
+        Cuboid { ranges }
+    }
+
+    /// From opposite corners, both included in the cuboid, in either order
+    pub fn from_corners(a: [T; N], b: [T; N]) -> Self {
+        Cuboid { ranges: array::from_fn(|axis| a[axis].min(b[axis])..a[axis].max(b[axis]) + T::one()) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(Range::is_empty)
+    }
+
+    /// Number of points in the cuboid. Panics if that doesn't fit in a usize.
+    pub fn volume(&self) -> usize {
+        if self.is_empty() { return 0 }
+        self.ranges.iter()
+            .map(|range| (range.end - range.start).to_usize().unwrap())
+            .try_fold(1usize, usize::checked_mul)
+            .expect("Volume overflows")
+    }
+
+    /// Moved by offset along each axis
+    pub fn translate(&self, offset: [T; N]) -> Self {
+        Cuboid { ranges: array::from_fn(|axis| self.ranges[axis].start + offset[axis]..self.ranges[axis].end + offset[axis]) }
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(range, value)| range.contains(value))
+    }
+
+    /// None if they don't overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Cuboid { ranges: array::from_fn(|axis| {
+            let (a, b) = (&self.ranges[axis], &other.ranges[axis]);
+            a.start.max(b.start)..a.end.min(b.end)
+        })};
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// As intersection(other).is_some(), without building the overlap
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.ranges.iter().zip(&other.ranges).all(|(a, b)| a.start.max(b.start) < a.end.min(b.end))
+    }
+
+    /// Disjoint cuboids covering the points of self that aren't in other, at most 2 per axis
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else { return vec![self.clone()] };
+        // slice off the parts before and after the overlap along each axis in turn,
+        // narrowing what's left to the overlap along that axis before moving on to the next
+        let mut pieces = Vec::new();
+        let mut remaining = self.clone();
+        for axis in 0..N {
+            let range = remaining.ranges[axis].clone();
+            for outside in [range.start..overlap.ranges[axis].start, overlap.ranges[axis].end..range.end] {
+                if outside.is_empty() { continue }
+                let mut piece = remaining.clone();
+                piece.ranges[axis] = outside;
+                pieces.push(piece);
+            }
+            remaining.ranges[axis] = overlap.ranges[axis].clone();
+        }
+        pieces
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for Cuboid<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.ranges).finish()
+    }
+}
+
+/// A set of points in N dimensions, made up of disjoint cuboids
+#[derive(Clone, Debug)]
+pub struct CuboidSet<T, const N: usize> {
+    cuboids: Vec<Cuboid<T, N>>,
+}
+
+impl<T: PrimInt, const N: usize> Default for CuboidSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt, const N: usize> CuboidSet<T, N> {
+    pub fn new() -> Self {
+        CuboidSet { cuboids: Vec::new() }
+    }
+
+    /// Disjoint, in no particular order
+    pub fn cuboids(&self) -> &[Cuboid<T, N>] {
+        &self.cuboids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    /// Number of points in the set
+    pub fn volume(&self) -> usize {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.cuboids.iter().any(|cuboid| cuboid.contains(point))
+    }
+
+    /// Adds the points of the cuboid that aren't already in the set
+    pub fn insert(&mut self, cuboid: Cuboid<T, N>) {
+        let mut new_pieces = if cuboid.is_empty() { vec![] } else { vec![cuboid] };
+        for existing in &self.cuboids {
+            if new_pieces.is_empty() { return }
+            new_pieces = new_pieces.iter().flat_map(|piece| piece.subtract(existing)).collect();
+        }
+        self.cuboids.extend(new_pieces);
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid<T, N>) {
+        self.cuboids = self.cuboids.iter().flat_map(|existing| existing.subtract(cuboid)).collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for cuboid in &other.cuboids {
+            union.insert(cuboid.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // pairwise overlaps of disjoint cuboids are themselves disjoint
+        let cuboids = self.cuboids.iter()
+            .flat_map(|a| other.cuboids.iter().filter_map(|b| a.intersection(b)))
+            .collect();
+        CuboidSet { cuboids }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for cuboid in &other.cuboids {
+            difference.remove(cuboid);
+        }
+        difference
+    }
+}
+
+impl<T: PrimInt, const N: usize> From<Cuboid<T, N>> for CuboidSet<T, N> {
+    fn from(cuboid: Cuboid<T, N>) -> Self {
+        let mut set = Self::new();
+        set.insert(cuboid);
+        set
+    }
+}
+
+/// Cuboids may overlap, points in several are only counted once
+impl<T: PrimInt, const N: usize> FromIterator<Cuboid<T, N>> for CuboidSet<T, N> {
+    fn from_iter<I: IntoIterator<Item=Cuboid<T, N>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for cuboid in iter {
+            set.insert(cuboid);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn from_corners_in_either_order() {
+        let cuboid = Cuboid::from_corners([3, -1, 2], [1, 1, 2]);
+        assert_eq!(cuboid, Cuboid::new([1..4, -1..2, 2..3]));
+        assert_eq!(cuboid.volume(), 9);
+        assert!(cuboid.contains(&[3, 1, 2]) && !cuboid.contains(&[4, 1, 2]));
+        assert_eq!(cuboid.translate([1, 0, -2]), Cuboid::new([2..5, -1..2, 0..1]));
+    }
+
+    #[test]
+    fn subtract_from_the_middle() {
+        let outer = Cuboid::new([0..3, 0..3, 0..3]);
+        let pieces = outer.subtract(&Cuboid::new([1..2, 1..2, 1..2]));
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<usize>(), 26);
+        assert!(pieces.iter().enumerate().all(|(i, a)| pieces[i + 1..].iter().all(|b| !a.intersects(b))));
+    }
+
+    #[test]
+    fn subtract_without_overlap() {
+        let cuboid = Cuboid::new([0..2, 0..2]);
+        assert_eq!(cuboid.subtract(&Cuboid::new([2..4, 0..2])), vec![cuboid.clone()]);
+        assert!(cuboid.subtract(&Cuboid::new([-1..3, -1..3])).is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: CuboidSet<i32, 2> = [Cuboid::new([0..4, 0..4]), Cuboid::new([2..6, 2..6])].into_iter().collect();
+        assert_eq!(a.volume(), 28);
+        let b = CuboidSet::from(Cuboid::new([3..10, 0..3]));
+        assert_eq!(a.intersection(&b).volume(), 5);
+        assert_eq!(a.union(&b).volume(), 28 + 21 - 5);
+        assert_eq!(a.difference(&b).volume(), 23);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn matches_set_of_points() {
+        let mut rng = StdRng::seed_from_u64(18);
+        let mut random_cuboid = || -> Cuboid<i32, 2> {
+            Cuboid::from_corners([rng.gen_range(0..10), rng.gen_range(0..10)], [rng.gen_range(0..10), rng.gen_range(0..10)])
+        };
+        let points = |set: &CuboidSet<i32, 2>| (0..10).flat_map(|x| (0..10).map(move |y| [x, y]))
+            .filter(|point| set.contains(point))
+            .collect::<HashSet<_>>();
+        for _ in 0..100 {
+            let a: CuboidSet<i32, 2> = (0..3).map(|_| random_cuboid()).collect();
+            let mut b: CuboidSet<i32, 2> = (0..3).map(|_| random_cuboid()).collect();
+            let (a_points, b_points) = (points(&a), points(&b));
+            assert_eq!(a.volume(), a_points.len());
+            assert_eq!(points(&a.union(&b)), &a_points | &b_points);
+            assert_eq!(points(&a.intersection(&b)), &a_points & &b_points);
+            assert_eq!(points(&a.difference(&b)), &a_points - &b_points);
+            let removed = random_cuboid();
+            b.remove(&removed);
+            assert_eq!(points(&b), b_points.into_iter().filter(|point| !removed.contains(point)).collect());
+        }
+    }
+}
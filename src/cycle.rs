@@ -0,0 +1,82 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The states of a deterministic process that eventually repeats: states 0..prefix happen once,
+/// then states prefix..prefix+period repeat forever.
+#[derive(Clone, Debug)]
+pub struct Cycle<S> {
+    /// Every distinct state in order, starting with the initial state
+    states: Vec<S>,
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl<S> Cycle<S> {
+    /// Applies step from the initial state until a state repeats. The state space must be finite, or this never returns.
+    pub fn find<F: FnMut(&S) -> S>(initial: S, step: F) -> Self where S: Hash + Eq + Clone {
+        Self::find_by_key(initial, step, S::clone)
+    }
+
+    /// As find, for states that can't be hashed themselves, or have cheaper keys. Two states are taken to be
+    /// the same if their keys are equal, so the key must capture everything that affects the following steps.
+    pub fn find_by_key<K, F, G>(initial: S, mut step: F, mut key: G) -> Self
+        where K: Hash + Eq, F: FnMut(&S) -> S, G: FnMut(&S) -> K {
+        let mut seen: HashMap<K, usize> = HashMap::new();
+        seen.insert(key(&initial), 0);
+        let mut states = vec![initial];
+        loop {
+            let next = step(states.last().unwrap());
+            let next_key = key(&next);
+            if let Some(&prefix) = seen.get(&next_key) {
+                let period = states.len() - prefix;
+                return Cycle { states, prefix, period }
+            }
+            seen.insert(next_key, states.len());
+            states.push(next);
+        }
+    }
+
+    /// The state after the given number of steps from the initial state
+    pub fn state_after(&self, steps: usize) -> &S {
+        if steps < self.states.len() {
+            &self.states[steps]
+        } else {
+            &self.states[self.prefix + (steps - self.prefix) % self.period]
+        }
+    }
+
+    /// Every distinct state in order, the first prefix of them only happening once
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_then_period() {
+        // 12 -> 6 -> 3 -> 6 -> 3 -> ...
+        let cycle = Cycle::find(12, |n| if n % 2 == 0 { n / 2 } else { n * 2 });
+        assert_eq!(cycle.states(), [12, 6, 3]);
+        assert_eq!((cycle.prefix, cycle.period), (1, 2));
+        assert_eq!(*cycle.state_after(3), 6);
+        assert_eq!(*cycle.state_after(1_000_000_001), 6);
+        assert_eq!(*cycle.state_after(1_000_000_000), 3);
+    }
+
+    #[test]
+    fn repeats_from_the_start() {
+        let cycle = Cycle::find(0, |n| (n + 1) % 5);
+        assert_eq!((cycle.prefix, cycle.period), (0, 5));
+        assert_eq!(*cycle.state_after(12), 2);
+    }
+
+    #[test]
+    fn key_ignores_history() {
+        // the step count grows forever, but only the position affects the following steps
+        let cycle = Cycle::find_by_key((0, 0), |&(position, steps)| ((position + 3) % 4, steps + 1), |&(position, _)| position);
+        assert_eq!((cycle.prefix, cycle.period), (0, 4));
+        assert_eq!(cycle.state_after(6).0, 2);
+    }
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
+use crate::{error::AocError, solution::Solution};
+
 const ONE: &str = "one";
 const TWO: &str = "two";
 const THREE: &str = "three";
@@ -12,25 +14,38 @@ const SEVEN: &str = "seven";
 const EIGHT: &str = "eight";
 const NINE: &str = "nine";
 
-fn main() {
-    let processor = LineProcessor2::new();
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
 
-    let total: u32 = rust_aoc::read_input(1).map(|line| processor.process(line)).sum();
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Ok(lines.collect())
+    }
 
-    // 1. 54953
-    // 2. 53868
-    println!("Total: {total}");
+    fn part1(input: &Self::Input) -> u32 {
+        let processor = LineProcessor1;
+        input.iter().map(|line| processor.process(line)).sum() // 54953
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let processor = LineProcessor2::new();
+        input.iter().map(|line| processor.process(line)).sum() // 53868
+    }
 }
 
 trait LineProcessor {
-    fn process(&self, line: String) -> u32;
+    fn process(&self, line: &str) -> u32;
 }
 
-#[allow(dead_code)]
 struct LineProcessor1;
 
 impl LineProcessor for LineProcessor1 {
-    fn process(&self, line: String) -> u32 {
+    fn process(&self, line: &str) -> u32 {
         let digits = line.chars().filter_map(|c| c.to_digit(10));
         let (first, last) = LineProcessor1::first_and_last(digits);
         as_number(first, last)
@@ -38,7 +53,6 @@ impl LineProcessor for LineProcessor1 {
 }
 
 impl LineProcessor1 {
-    #[allow(dead_code)]
     fn first_and_last<T: Copy>(iter: impl std::iter::Iterator<Item=T>) -> (T, T) {
         let mut iter = iter.peekable();
         let first = *iter.peek().unwrap();
@@ -53,13 +67,13 @@ struct LineProcessor2 {
 }
 
 impl LineProcessor for LineProcessor2 {
-    fn process(&self, line: String) -> u32 {
-        let first = self.re.find(&line).unwrap();
+    fn process(&self, line: &str) -> u32 {
+        let first = self.re.find(line).unwrap();
         let first_val = self.match_as_value(&first);
 
         let mut last_val = first_val;
         let mut last_start = first.start(); 
-        while let Some(m) = self.re.find_at(&line, last_start + 1) {
+        while let Some(m) = self.re.find_at(line, last_start + 1) {
             last_val = self.match_as_value(&m);
             last_start = m.start();
         }
@@ -88,7 +102,7 @@ impl LineProcessor2 {
 
     fn match_as_value(&self, m: &regex::Match) -> u32 {
         let m = m.as_str();
-        self.text_strings.get(m).map_or_else(|| LineProcessor2::string_as_digit(&m), |v| *v)
+        self.text_strings.get(m).map_or_else(|| LineProcessor2::string_as_digit(m), |v| *v)
     }
 
     fn string_as_digit(str: &str) -> u32 {
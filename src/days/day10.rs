@@ -1,46 +1,64 @@
-use std::collections::{BTreeMap, HashMap};
-use std::io::{Error, ErrorKind};
+use std::collections::BTreeMap;
 use std::ops::Deref;
-use rust_aoc::point::Point;
-use rust_aoc::direction::Direction;
+use crate::error::AocError;
+use crate::grid::GridCell;
+use crate::point::Point;
+use crate::direction::Direction;
+use crate::solution::Solution;
+use crate::sparse_grid::SparseGrid;
 
-type Grid = HashMap<Point, Tile>;
+type Grid = SparseGrid<Tile>;
 
-fn main() {
-    let mut grid = HashMap::new();
-    let mut start = Point {x: -1, y: -1};
-    
-    rust_aoc::process_grid(10, |p, c| { parse(p, c, &mut grid, &mut start) });
+pub struct Day10;
 
-    println!("Grid: {}", grid.len());
-    println!("Start: {start}");
+impl Solution for Day10 {
+    const DAY: u32 = 10;
 
-    let start_pipes = Tile::tiles_connecting_to_point(start, &grid);
-    assert!(start_pipes.len() == 2);
-    
-    let start = Tile::infer_from_points(start, start_pipes[0].point, start_pipes[1].point);
-    grid.insert(start.point, start); // fill in 'S' tile of grid
+    type Input = Pipes;
+    type Answer1 = usize;
+    type Answer2 = i64;
 
-    let loop_pipes = Tile::traverse_loop(start, &grid);
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut grid = Grid::new();
+        let mut start = Point {x: -1, y: -1};
 
-    println!("Loop size: {}", loop_pipes.len());
-    println!("Distance to midpoint: {}", loop_pipes.len() / 2); // 6717
+        crate::try_process_grid_lines(lines, |p, c| parse(p, c, &mut grid, &mut start))?;
 
-    let contained_tiles = count_enclosed_cells(loop_pipes);
+        let start_pipes = Tile::tiles_connecting_to_point(start, &grid);
+        if start_pipes.len() != 2 {
+            return Err(AocError::new(format!("Start {start:?} should connect to exactly 2 pipes, found {}", start_pipes.len())))
+        }
+
+        let start = Tile::infer_from_points(start, start_pipes[0].point, start_pipes[1].point);
+        grid.insert(start.point, start); // fill in 'S' tile of grid
+
+        Ok(Pipes { grid, start })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let loop_pipes = Tile::traverse_loop(input.start, &input.grid);
+        loop_pipes.len() / 2 // 6717
+    }
 
-    println!("Contains {contained_tiles} tiles"); // 381
+    fn part2(input: &Self::Input) -> i64 {
+        let loop_pipes = Tile::traverse_loop(input.start, &input.grid);
+        count_enclosed_cells(loop_pipes) // 381
+    }
+}
 
+pub struct Pipes {
+    grid: Grid,
+    start: Tile,
 }
 
-fn parse(point: Point, c: char, grid: &mut Grid, start: &mut Point) {
-    if c == '.' {
-            return
-    } else if c == 'S' { 
+fn parse(point: Point, c: char, grid: &mut Grid, start: &mut Point) -> Result<(), AocError> {
+    if c == 'S' {
         *start = point;
-    } else {
-        let kind = TileKind::try_from(c).unwrap();
+    } else if c != '.' {
+        let kind = TileKind::try_from(c)?;
         grid.insert(point, Tile { kind, point });
     }
+    Ok(())
 }
 
 fn count_enclosed_cells(loop_tiles: Vec<Tile>) -> i64 {
@@ -94,9 +112,9 @@ impl Tile {
 
     fn tiles_connecting_to_point(p: Point, grid: &Grid) -> Vec<Tile> {
         p.orthogonal_neighbours().iter()
-            .map(|p| grid.get(p)).filter(Option::is_some).map(Option::unwrap)
+            .filter_map(|p| grid.get(p))
             .filter(|tile| tile.connects_to(&p))
-            .map(|tile| *tile)
+            .copied()
             .collect()
     }
 
@@ -118,7 +136,7 @@ impl Tile {
             loop_tiles.push(current);
             // Should be exactly 1 choice
             let next_points = current.get_connected_points().into_iter().filter(|p| *p != previous.point);
-            let next_point = rust_aoc::assert_single(next_points);
+            let next_point = crate::assert_single(next_points);
 
             previous = current;
             current = *grid.get(&next_point).unwrap();
@@ -128,6 +146,19 @@ impl Tile {
     }
 }
 
+impl GridCell for Tile {
+    fn to_char(&self) -> char {
+        match self.kind {
+            TileKind::NorthSouth => '|',
+            TileKind::EastWest => '-',
+            TileKind::NorthEast => 'L',
+            TileKind::NorthWest => 'J',
+            TileKind::SouthEast => 'F',
+            TileKind::SouthWest => '7',
+        }
+    }
+}
+
 impl Deref for Tile {
     type Target = Point;
 
@@ -170,7 +201,7 @@ impl TileKind {
 }
 
 impl TryFrom<char> for TileKind {
-    type Error = Error;
+    type Error = AocError;
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '|' => Ok(TileKind::NorthSouth),
@@ -179,26 +210,26 @@ impl TryFrom<char> for TileKind {
             'J' => Ok(TileKind::NorthWest),
             'F' => Ok(TileKind::SouthEast),
             '7' => Ok(TileKind::SouthWest),
-            _ => Err(Error::new(ErrorKind::InvalidInput, format!("Unknown tile: {value}")))
+            _ => Err(AocError::invalid(value, "Unknown tile"))
         }
     }
 }
 
 impl TryFrom<(Direction, Direction)> for TileKind {
-    type Error = Error;
+    type Error = AocError;
 
     fn try_from((d1, d2): (Direction, Direction)) -> Result<Self, Self::Error> {
         let mut dirs = vec![d1, d2];
         dirs.sort();
 
-        if let Some(kind) = TileKind::all().iter().filter(|kind| {
+        if let Some(kind) = TileKind::all().iter().find(|kind| {
             let mut kind_dirs: Vec<_> = kind.directions().into_iter().collect();
             kind_dirs.sort();
             dirs == kind_dirs
-        }).next() {
+        }) {
             Ok(*kind)
         } else {
-            Err(Error::new(ErrorKind::InvalidInput, format!("({d1:?}, {d2:?}) does not correspond to a tile")))
+            Err(AocError::new(format!("({d1:?}, {d2:?}) does not correspond to a tile")))
         }
     }
 }
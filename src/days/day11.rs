@@ -0,0 +1,112 @@
+use std::collections::BTreeSet;
+use crate::{error::AocError, grid::GridCell, point::Point, solution::Solution, sparse_grid::SparseGrid};
+
+type Cells = SparseGrid<Galaxy>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut cells = Cells::new();
+
+        crate::process_grid_lines(lines, |p, c| { parse(p, c, &mut cells) });
+
+        Ok(Grid::new(cells))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::get_total_distance(input) // 9233514
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::get_total_distance(input) // 363293506944
+    }
+}
+
+trait Part {
+    fn empty_cell_size() -> usize;
+
+    fn get_total_distance(grid: &Grid) -> usize {
+        let empty_size_additional_factor = Self::empty_cell_size() - 1;
+        let mut total_dist: usize = 0;
+        let galaxies: Vec<Point> = grid.cells.points().collect();
+        for_each_pair(&galaxies, |from, to| {
+            total_dist += <u64 as TryInto<usize>>::try_into(from.orthogonal_distance(to)).unwrap();
+
+            let (xmin, xmax) = ordered(from.x, to.x); 
+            let (ymin, ymax) = ordered(from.y, to.y);
+            total_dist += grid.doubled_cols.range(xmin..xmax).count() * empty_size_additional_factor;
+            total_dist += grid.doubled_rows.range(ymin..ymax).count() * empty_size_additional_factor;
+        });
+        total_dist
+    }
+}
+
+struct Part1;
+
+impl Part for Part1 {
+    fn empty_cell_size() -> usize {
+        2
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    fn empty_cell_size() -> usize {
+        1000000
+    }
+}
+
+fn parse(point: Point, c: char, grid: &mut Cells) {
+    if c == '#' {
+        grid.insert(point, Galaxy);
+    }
+}
+
+struct Galaxy;
+
+impl GridCell for Galaxy {
+    fn to_char(&self) -> char {
+        '#'
+    }
+}
+
+pub struct Grid {
+    cells: Cells,
+    doubled_rows: BTreeSet<i64>,
+    doubled_cols: BTreeSet<i64>,
+}
+
+impl Grid {
+    /// Doubled rows/cols are the empty ones, up to the last galaxy
+    fn new(cells: Cells) -> Grid {
+        let max = cells.max().unwrap();
+        let doubled_rows: BTreeSet<i64> = (0..max.y).filter(|y| cells.row_len(*y) == 0).collect();
+        let doubled_cols: BTreeSet<i64> = (0..max.x).filter(|x| cells.column_len(*x) == 0).collect();
+
+        Grid { cells, doubled_rows, doubled_cols }
+    }
+}
+
+fn for_each_pair<T, F: FnMut(&T, &T)>(values: &[T], mut f: F) {
+    for (i, first) in values.iter().enumerate() {
+        for second in values.iter().skip(i + 1) {
+            f(first, second);
+        }
+    }
+}
+
+fn ordered(a: i64, b: i64) -> (i64, i64) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
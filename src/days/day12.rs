@@ -1,38 +1,51 @@
-use std::{collections::HashMap, io::{Error, ErrorKind}, str::FromStr};
+use std::{collections::HashMap, str::FromStr};
 
-fn main() {
-    println!("Part 1");
-    Part1::process();
-    println!("Part 2");
-    Part2::process();
+use crate::{error::{self, AocError}, solution::Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<Configuration>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        crate::parse_lines(lines)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::process(input) // 6827
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::process(input) // 1537505634471
+    }
 }
 
 trait Part {
-    fn parse(s: String) -> Configuration;
+    fn unfold(config: &Configuration) -> Configuration;
 
-    fn process() {
-        let total: usize = rust_aoc::read_input(12).map(Self::parse)
+    fn process(configs: &[Configuration]) -> usize {
+        configs.iter().map(Self::unfold)
             .map(Configuration::permutations)
-            .sum();
-        // 1. 6827
-        // 2. 1537505634471
-        println!("Total ways: {total}");
+            .sum()
     }
 }
 
 struct Part1;
 
 impl Part for Part1 {
-    fn parse(s: String) -> Configuration {
-        s.parse().unwrap()
+    fn unfold(config: &Configuration) -> Configuration {
+        config.clone()
     }
 }
 
 struct Part2;
 
 impl Part for Part2 {
-    fn parse(s: String) -> Configuration {
-        let config: Configuration = s.parse().unwrap();
+    fn unfold(config: &Configuration) -> Configuration {
         let mut states = config.states.clone();
         for _ in 1..5 {
             states.push(State::Unknown);
@@ -111,7 +124,7 @@ impl MemoTable {
 }
 
 #[derive(Clone, Debug)]
-struct Configuration {
+pub struct Configuration {
     states: Vec<State>,
     groups: Vec<usize>,
 }
@@ -123,12 +136,12 @@ impl Configuration {
 }
 
 impl FromStr for Configuration {
-    type Err = Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (states, groups) = rust_aoc::split_in_two(s, ' ');
-        let states = states.chars().map(State::try_from).map(Result::unwrap).collect();
-        let groups = groups.split(',').map(str::parse).map(Result::unwrap).collect();
+        let (states, groups) = s.split_once(' ').ok_or_else(|| AocError::new("Expected 'states groups'"))?;
+        let states = states.chars().map(State::try_from).collect::<Result<_, _>>()?;
+        let groups = groups.split(',').map(error::parse).collect::<Result<_, _>>()?;
         Ok(Configuration { states, groups })
     }
 }
@@ -141,14 +154,14 @@ enum State {
 }
 
 impl TryFrom<char> for State {
-    type Error = Error;
+    type Error = AocError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '.' => Ok(State::Blank),
             '#' => Ok(State::Occupied),
             '?' => Ok(State::Unknown),
-            _ => Err(Error::new(ErrorKind::InvalidInput, format!("Unknown state {value}")))
+            _ => Err(AocError::invalid(value, "Unknown state"))
         }
     }
 }
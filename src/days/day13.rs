@@ -0,0 +1,67 @@
+use crate::{error::AocError, grid::Grid, solution::Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Vec<Pattern>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Ok(lines.map(|s| s.chars().collect()).collect::<Vec<_>>()
+            .split(|row: &Vec<char>| row.is_empty()).map(|pattern| Grid::new(pattern.to_owned())).collect())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::process(input) // 35538
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::process(input) // 30442
+    }
+}
+
+type Pattern = Grid<char>;
+
+trait Part: Sized {
+    fn get_required_errors() -> usize;
+
+    fn process(patterns: &[Pattern]) -> usize {
+        patterns.iter().map(Self::summarise_symmetry).sum()
+    }
+
+    /// Columns of the pattern are rows of its transpose, so only reflections between rows need finding
+    fn summarise_symmetry(pattern: &Pattern) -> usize {
+        Self::find_symmetry(&pattern.transpose()).unwrap_or_else(|| Self::find_symmetry(pattern).unwrap() * 100)
+    }
+
+    /// Number of rows above a line that the pattern is reflected in
+    fn find_symmetry(pattern: &Pattern) -> Option<usize> {
+        let height = pattern.height as usize;
+        (1..height).find(|line| {
+            let lines_to_compare = usize::min(*line, height - line);
+            let errors: usize = (0..lines_to_compare)
+                .map(|y| pattern.row(line - y - 1).iter().zip(pattern.row(line + y)).filter(|(a, b)| a != b).count())
+                .sum();
+            errors == Self::get_required_errors()
+        })
+    }
+}
+
+struct Part1;
+
+impl Part for Part1 {
+    fn get_required_errors() -> usize {
+        0
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    fn get_required_errors() -> usize {
+        1
+    }
+}
@@ -0,0 +1,152 @@
+use std::cmp::max;
+
+use crate::{cycle::Cycle, direction::Direction, error::AocError, grid::GridCell, point::Point, solution::Solution, sparse_grid::SparseGrid};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Grid;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Cells::new();
+        crate::process_grid_lines(lines, |p, c| {
+            width = max(width, p.x+1);
+            height = max(height, p.y+1);
+            parse(p, c, &mut cells);
+        });
+
+        Ok(Grid { cells, width, height })
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        Part1::process(input) // 108857
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        Part2::process(input) // 95273
+    }
+}
+
+trait Part {
+    fn tilt(grid: &mut Grid);
+
+    fn process(grid: &Grid) -> i64 {
+        let mut grid = grid.clone();
+
+        Self::tilt(&mut grid);
+
+        Self::calculate_load(&grid)
+    }
+
+    fn calculate_load(grid: &Grid) -> i64 {
+        grid.cells.iter()
+            .filter(|(_, r)| **r == Rock::Round)
+            .map(|(p, _)| grid.height - p.y)
+            .sum()
+    }
+}
+
+
+struct Part1;
+
+impl Part for Part1 {
+    fn tilt(grid: &mut Grid) {
+        tilt(Direction::North, grid);
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    /// Strategy: Grid is 100 x 100 with ~3k rocks (with ~50% round rocks).
+    /// The rocks settle into a loop after ~100 cycles, so keep cycling until a state repeats,
+    /// keyed by the rock positions since the cells can't be hashed themselves.
+    /// Then the state after a billion cycles is just the matching state within the loop.
+    fn tilt(grid: &mut Grid) {
+        let states = Cycle::find_by_key(grid.clone(), |grid| {
+            let mut grid = grid.clone();
+            cycle(&mut grid);
+            grid
+        }, Grid::get_sorted_rock_positions);
+        *grid = states.state_after(1000000000).clone();
+    }
+}
+
+fn cycle(grid: &mut Grid) {
+    for dir in [Direction::North, Direction::West, Direction::South, Direction::East] {
+        tilt(dir, grid);
+    }
+}
+
+/// Rocks closest to the edge they're rolling towards go first, so every rock in the way has already stopped
+fn tilt(dir: Direction, grid: &mut Grid) {
+    let mut rocks: Vec<Point> = grid.cells.iter()
+        .filter(|(_, &rock)| rock == Rock::Round)
+        .map(|(p, _)| p)
+        .collect();
+    if matches!(dir, Direction::South | Direction::East) { rocks.reverse() }
+    for p in rocks {
+        let mut new_point = p;
+        while can_roll(&new_point, dir, grid) { new_point += dir }
+        if new_point != p {
+            grid.cells.remove(&p);
+            grid.cells.insert(new_point, Rock::Round);
+        }
+    }
+}
+
+fn can_roll(point: &Point, dir: Direction, grid: &Grid) -> bool {
+    let next = *point + dir;
+    is_in_bounds(&next, grid) && !grid.cells.contains(&next)
+}
+
+fn is_in_bounds(point: &Point, grid: &Grid) -> bool {
+    point.x >= 0 && point.y >= 0 && point.x < grid.width && point.y < grid.height
+}
+
+fn parse(p: Point, c: char, cells: &mut Cells) {
+    match c {
+        'O' => { cells.insert(p, Rock::Round); },
+        '#' => { cells.insert(p, Rock::Fixed); },
+        _ => {}
+    }
+}
+
+/// Grid is around 100 x 100, and quite densely populated,
+/// so a simple array might actually be faster, but --release build still takes <1s.
+/// The rocks don't necessarily reach the edges, so the grid's size is kept separately.
+type Cells = SparseGrid<Rock>;
+
+#[derive(Clone)]
+pub struct Grid {
+    cells: Cells,
+    width: i64,
+    height: i64,
+}
+
+impl Grid {
+    fn get_sorted_rock_positions(&self) -> Vec<Point> {
+        self.cells.points().collect()
+    }
+}
+
+impl GridCell for Rock {
+    fn to_char(&self) -> char {
+        match self {
+            Rock::Round => 'O',
+            Rock::Fixed => '#',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Rock {
+    Round,
+    Fixed,
+}
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use linked_hash_map::LinkedHashMap;
+
+use crate::{error::{self, AocError}, solution::Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Vec<Step>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(mut lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let line = lines.next().ok_or_else(|| AocError::new("Empty input"))?;
+        line.split(',').map(str::parse).collect::<Result<_, AocError>>().map_err(|e| e.at_line(0, &line))
+    }
+
+    fn part1(steps: &Self::Input) -> usize {
+        steps.iter().map(|step| hash(&step.text)).sum() // 505379
+    }
+
+    fn part2(steps: &Self::Input) -> usize {
+        let mut boxes = Boxes::new();
+        for step in steps {
+            boxes.apply(step);
+        }
+
+        boxes.get_total_power() // 263211
+    }
+}
+
+struct Boxes {
+    boxes: Vec<LinkedHashMap<String, usize>>,
+}
+
+impl Boxes {
+    fn new() -> Boxes {
+        Boxes { boxes: (0..256).map(|_| LinkedHashMap::new()).collect() }
+    }
+
+    fn apply(&mut self, step: &Step) {
+        match step.action {
+            Action::Remove => self.remove(&step.label),
+            Action::Insert(lens) => self.update(&step.label, lens),
+        }
+    }
+
+    fn remove(&mut self, label: &str) {
+        let hash = hash(label);
+        self.boxes[hash].remove(label);
+    }
+
+    fn update(&mut self, label: &str, lens: usize) {
+        let hash = hash(label);
+        self.boxes[hash].entry(String::from(label)).and_modify(|stored_lens| *stored_lens = lens).or_insert(lens);
+    }
+
+    fn get_total_power(&self) -> usize {
+        self.boxes.iter().enumerate().map(|(box_num, lenses)| {
+            let box_num = box_num + 1;
+            box_num * lenses.values().enumerate().map(|(slot, lens)| (slot + 1) * lens).sum::<usize>()
+        }).sum()
+    }
+}
+
+pub struct Step {
+    /// The whole step, which is what part 1 hashes
+    text: String,
+    label: String,
+    action: Action,
+}
+
+enum Action {
+    Remove,
+    Insert(usize),
+}
+
+impl FromStr for Step {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, action) = if let Some(label) = s.strip_suffix('-') {
+            (label, Action::Remove)
+        } else if let Some((label, lens)) = s.split_once('=') {
+            (label, Action::Insert(error::parse(lens)?))
+        } else {
+            return Err(AocError::invalid(s, "Invalid step"))
+        };
+        Ok(Step { text: String::from(s), label: String::from(label), action })
+    }
+}
+
+fn hash(s: &str) -> usize {
+    let mut value = 0;
+    for b in s.bytes() {
+        value += b as usize;
+        value *= 17;
+        value %= 256;
+    }
+    value
+}
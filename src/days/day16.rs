@@ -1,37 +1,44 @@
-use std::{collections::HashSet, io::{Error, ErrorKind}};
+use std::collections::HashSet;
 
-use rust_aoc::{direction::Direction, point::Point, BreadthFirstSearch};
+use crate::{direction::Direction, error::AocError, grid::GridCell, point::Point, solution::Solution, BreadthFirstSearch};
 
-fn main() {
-    let layout: Vec<Vec<TileKind>> = rust_aoc::read_input(16)
-        .map(|line| line.chars().map(TileKind::try_from).map(Result::unwrap).collect())
-        .collect();
+pub struct Day16;
 
-    let start = Point {x: 0, y: 0};
-    let start_dir = Direction::East;
+impl Solution for Day16 {
+    const DAY: u32 = 16;
 
-    let total = count_energized(start, start_dir, &layout);
+    type Input = crate::grid::Grid<TileKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("Part 1: Energized cells: {total}"); // 7434
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        crate::grid::Grid::try_parse_lines(lines, TileKind::try_from)
+    }
+
+    fn part1(layout: &Self::Input) -> usize {
+        let start = Point {x: 0, y: 0};
+        let start_dir = Direction::East;
 
-    let width = layout[0].len() as i64;
-    let height = layout.len() as i64;
+        count_energized(start, start_dir, layout) // 7434
+    }
 
-    let maximum: usize = (0..width).map(|x| (Point {x, y: 0}, Direction::South))
-        .chain((0..width).map(|x| (Point {x, y: height - 1}, Direction::North)))
-        .chain((0..height).map(|y| (Point {x: 0, y}, Direction::East)))
-        .chain((0..height).map(|y| (Point {x: width - 1, y}, Direction::West)))
-        .map(|(start, start_dir)| count_energized(start, start_dir, &layout))
-        .max().unwrap();
+    fn part2(layout: &Self::Input) -> usize {
+        let (width, height) = (layout.width, layout.height);
 
-    println!("Part 2: Maximum: {maximum}"); // 8183
+        (0..width).map(|x| (Point {x, y: 0}, Direction::South))
+            .chain((0..width).map(|x| (Point {x, y: height - 1}, Direction::North)))
+            .chain((0..height).map(|y| (Point {x: 0, y}, Direction::East)))
+            .chain((0..height).map(|y| (Point {x: width - 1, y}, Direction::West)))
+            .map(|(start, start_dir)| count_energized(start, start_dir, layout))
+            .max().unwrap() // 8183
+    }
 }
 
 struct EnergizedSearch<'a> {
     grid: &'a mut Grid,
 }
 
-impl rust_aoc::BreadthFirstSearch for EnergizedSearch<'_> {
+impl BreadthFirstSearch for EnergizedSearch<'_> {
     type Node = (Point, Direction);
 
     fn mark(&mut self, (p, dir): &(Point, Direction)) -> bool {
@@ -46,16 +53,14 @@ impl rust_aoc::BreadthFirstSearch for EnergizedSearch<'_> {
     }
 }
 
-fn count_energized(start: Point, start_dir: Direction, layout: &Vec<Vec<TileKind>>) -> usize {
-    let mut grid: Grid = layout.iter()
-        .map(|row| row.iter().map(|kind| Cell::new(*kind)).collect())
-        .collect();
+fn count_energized(start: Point, start_dir: Direction, layout: &crate::grid::Grid<TileKind>) -> usize {
+    let mut grid: Grid = layout.clone().map(|_, kind| Cell::new(kind));
     let search = EnergizedSearch { grid: &mut grid };
     search.search((start, start_dir));
     grid.iter().filter(|cell| !cell.energized_directions.is_empty()).count()
 }
 
-type Grid = rust_aoc::grid::Grid<Cell>;
+type Grid = crate::grid::Grid<Cell>;
 
 struct Cell {
     kind: TileKind,
@@ -69,7 +74,7 @@ impl Cell {
 }
 
 #[derive(Copy, Clone)]
-enum TileKind {
+pub enum TileKind {
     Empty,
     UpRightMirror,
     UpLeftMirror,
@@ -121,8 +126,27 @@ impl TileKind {
     }
 }
 
+/// Energized tiles are drawn as #
+impl GridCell for Cell {
+    fn to_char(&self) -> char {
+        if self.energized_directions.is_empty() { self.kind.to_char() } else { '#' }
+    }
+}
+
+impl GridCell for TileKind {
+    fn to_char(&self) -> char {
+        match self {
+            TileKind::Empty => '.',
+            TileKind::UpRightMirror => '/',
+            TileKind::UpLeftMirror => '\\',
+            TileKind::VerticalSplitter => '|',
+            TileKind::HorizontalSplitter => '-',
+        }
+    }
+}
+
 impl TryFrom<char> for TileKind {
-    type Error = Error;
+    type Error = AocError;
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '.' => Ok(TileKind::Empty),
@@ -130,7 +154,7 @@ impl TryFrom<char> for TileKind {
             '\\' => Ok(TileKind::UpLeftMirror),
             '|' => Ok(TileKind::VerticalSplitter),
             '-' => Ok(TileKind::HorizontalSplitter),
-            _ => Err(Error::new(ErrorKind::InvalidInput, format!("Unknown tile: {value}")))
+            _ => Err(AocError::invalid(value, "Unknown tile"))
         }
     }
 }
\ No newline at end of file
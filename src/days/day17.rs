@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use crate::{direction::Direction, error::AocError, grid::Grid, point::Point, solution::Solution, AStar, Dijkstra};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Grid::try_parse_lines(lines, |c| c.to_digit(10).map(|n| n as usize).ok_or_else(|| AocError::invalid(c, "Expected a digit")))
+    }
+
+    fn part1(tiles: &Self::Input) -> usize {
+        min_cost(tiles, Constraints { min_steps: 1, max_steps: 3 }) // 635
+    }
+
+    fn part2(tiles: &Self::Input) -> usize {
+        min_cost(tiles, Constraints { min_steps: 4, max_steps: 10 }) // 734
+    }
+}
+
+fn min_cost(tiles: &Grid<usize>, constraints: Constraints) -> usize {
+    GridSearch::new(tiles, constraints).search(starts())
+}
+
+fn starts() -> Vec<Crucible> {
+    let start_point = Point {x: 0, y: 0};
+    // 'start' is the only case where steps=0 and direction irrelevant, since we haven't moved yet
+    [Direction::East, Direction::South]
+        .map(|last_dir| Crucible { point: start_point, last_dir, steps: 0 })
+        .into_iter().collect()
+}
+
+struct Constraints {
+    min_steps: usize,
+    max_steps: usize,
+}
+
+struct GridSearch<'a> {
+    tiles: &'a Grid<usize>,
+    // Point + Direction => Steps => Cost
+    // A state is an improvement if, for that point, for its number of steps and all below it,
+    // none of them have a better cost.
+    state_upper_bounds: HashMap<Crucible, usize>,
+    constraints: Constraints,
+}
+
+impl<'a> GridSearch<'a> {
+    fn new(tiles: &'a Grid<usize>, constraints: Constraints) -> Self {
+        GridSearch { tiles, state_upper_bounds: HashMap::new(), constraints }
+    }
+
+    fn end_point(&self) -> Point {
+        Point {x: self.tiles.width - 1, y: self.tiles.height - 1}
+    }
+}
+
+impl AStar for GridSearch<'_> {
+    fn heuristic(&self, state: &Crucible) -> usize {
+        // every tile costs at least 1 to enter
+        crate::manhattan_heuristic(&state.point, &self.end_point(), 1)
+    }
+}
+
+impl Dijkstra for GridSearch<'_> {
+    type State = Crucible;
+
+    fn is_end(&self, &Crucible { point, steps, .. }: &Crucible) -> bool {
+        point == self.end_point() && steps >= self.constraints.min_steps
+    }
+
+    fn neighbours(&self, value: &Self::State) -> Vec<(usize, Self::State)> {
+        let &Crucible { point, last_dir, steps } = value;
+        
+        Direction::all().into_iter()
+            .filter(|d| *d != last_dir.opposite() && (*d == last_dir || steps >= self.constraints.min_steps))
+            .map(|d| Crucible {
+                point: point + d,
+                last_dir: d, 
+                steps: if last_dir == d { steps + 1 } else { 1 } 
+            })
+            .filter(|Crucible { point, steps, .. }| *steps <= self.constraints.max_steps && self.tiles.is_in_bounds(point) )
+            .map(|crucible| {
+                let cost = self.tiles[&crucible.point];
+                (cost, crucible)
+            })
+            .collect()
+    }
+
+    fn try_improve(&mut self, state: &Self::State, cost: usize) -> bool {
+        match self.state_upper_bounds.get_mut(state) {
+            Some(existing_cost) => {
+                if *existing_cost > cost {
+                    *existing_cost = cost;
+                    true
+                } else {
+                    false
+                }
+            },
+            None => {
+                self.state_upper_bounds.insert(*state, cost);
+                true
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Crucible {
+    point: Point,
+    last_dir: Direction,
+    steps: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DijkstraPath, SearchStats};
+
+    fn route(tiles: &Grid<usize>, constraints: Constraints) -> Option<DijkstraPath<Crucible>> {
+        GridSearch::new(tiles, constraints).search_path(starts())
+    }
+
+    /// The map with the cheapest route drawn on it, as in the puzzle description e.g.
+    /// 2>>34^>>>1323
+    /// 32v>>>35v5623
+    fn draw_route(tiles: &Grid<usize>, constraints: Constraints) -> Option<String> {
+        let route = route(tiles, constraints)?;
+        // first state is the start, which hasn't moved in any direction yet
+        let moves = &route.states[1..];
+        let moved = |dir| moves.iter().filter(move |crucible| crucible.last_dir == dir).map(|crucible| &crucible.point);
+        let digits = tiles.clone().map(|_, n| char::from_digit(n as u32, 10).unwrap());
+        let drawn = digits.highlight(moved(Direction::North), '^')
+            .with(moved(Direction::East), '>')
+            .with(moved(Direction::South), 'v')
+            .with(moved(Direction::West), '<');
+        Some(drawn.to_string())
+    }
+
+    /// Plain Dijkstra and A* over the same state space.
+    /// On the example, A* expands 1466 states rather than 1557 for part 1, and 868 rather than 1461 for part 2.
+    fn compare_searches(tiles: &Grid<usize>, min_steps: usize, max_steps: usize) -> (SearchStats, SearchStats) {
+        let dijkstra = GridSearch::new(tiles, Constraints { min_steps, max_steps }).search_with_stats(starts());
+        let a_star = GridSearch::new(tiles, Constraints { min_steps, max_steps }).a_star_search(starts());
+        (dijkstra, a_star)
+    }
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    fn example() -> Grid<usize> {
+        Day17::parse(EXAMPLE.lines().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn example_answers() {
+        let tiles = example();
+        assert_eq!(Day17::part1(&tiles), 102);
+        assert_eq!(Day17::part2(&tiles), 94);
+    }
+
+    #[test]
+    fn draws_example_route() {
+        let drawn = draw_route(&example(), Constraints { min_steps: 1, max_steps: 3 }).unwrap();
+        assert_eq!(drawn, "\
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>");
+    }
+
+    #[test]
+    fn a_star_expands_fewer_states() {
+        let tiles = example();
+        for (min_steps, max_steps) in [(1, 3), (4, 10)] {
+            let (dijkstra, a_star) = compare_searches(&tiles, min_steps, max_steps);
+            assert_eq!(dijkstra.cost, a_star.cost);
+            assert!(a_star.expanded < dijkstra.expanded, "A* expanded {} states, Dijkstra {}", a_star.expanded, dijkstra.expanded);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{direction::Direction, error::{self, AocError}, point::Point, polygon::Polygon, solution::Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Vec<(Direction, i64, (Direction, i64))>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        lines.enumerate()
+            .map(|(i, line)| parse_line(&line).map_err(|e| e.at_line(i, &line)))
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> i64 {
+        calc_enclosed_cells(instructions.iter().map(|(dir, steps, _)| (*dir, *steps))) // 41019
+    }
+
+    fn part2(instructions: &Self::Input) -> i64 {
+        calc_enclosed_cells(instructions.iter().map(|(_, _, colour_instructions)| *colour_instructions)) // 96116995735219
+    }
+}
+
+fn calc_enclosed_cells(instructions: impl Iterator<Item=(Direction, i64)>) -> i64 {
+    Polygon::from_moves(Point {x: 0, y: 0}, instructions).enclosed_cells()
+}
+
+fn parse_line(line: &str) -> Result<(Direction, i64, (Direction, i64)), AocError> {
+    let parts: Vec<_> = line.split_ascii_whitespace().collect();
+    let [dir, steps, colour] = parts[..] else {
+        return Err(AocError::new("Expected a direction, steps and colour"))
+    };
+    let dir = parse_direction(dir)?;
+    let steps = error::parse(steps)?;
+    let colour_intructions = parse_colour(colour)?;
+    Ok((dir, steps, colour_intructions))
+}
+
+fn parse_direction(dir: &str) -> Result<Direction, AocError> {
+    match dir {
+        "R" | "0" => Ok(Direction::East),
+        "D" | "1" => Ok(Direction::South),
+        "L" | "2" => Ok(Direction::West),
+        "U" | "3" => Ok(Direction::North),
+        _ => Err(AocError::invalid(dir, "Unrecognised direction"))
+    }
+}
+
+fn parse_colour(colour: &str) -> Result<(Direction, i64), AocError> {
+    // '(#FF00FF)'
+    let invalid = || AocError::invalid(colour, "Expected a colour like (#70c710)");
+    if !colour.starts_with("(#") || !colour.ends_with(')') || colour.len() != 9 { return Err(invalid()) }
+    let steps = colour.get(2..7).and_then(|hex| i64::from_str_radix(hex, 16).ok()).ok_or_else(invalid)?;
+    let dir = colour.get(7..8).ok_or_else(invalid).and_then(parse_direction)?;
+    Ok((dir, steps))
+}
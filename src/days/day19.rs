@@ -0,0 +1,257 @@
+use std::{array, collections::HashMap, ops::{Index, IndexMut, Range}, str::FromStr};
+
+use enum_map::{Enum, EnumMap};
+
+use crate::{cuboid::{Cuboid, CuboidSet}, error::{self, AocError}, solution::Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = System;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut lines = lines.enumerate();
+        let mut workflows = HashMap::new();
+        for (i, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+            let workflow: Workflow = line.parse().map_err(|e: AocError| e.at_line(i, &line))?;
+            workflows.insert(workflow.id.clone(), workflow);
+        }
+        if !workflows.contains_key("in") { return Err(AocError::new("No 'in' workflow")) }
+        if let Some(missing) = workflows.values().flat_map(|workflow| &workflow.rules).map(Rule::destination)
+            .find(|destination| !matches!(destination.as_str(), "A" | "R") && !workflows.contains_key(*destination)) {
+            return Err(AocError::invalid(missing.as_str(), "Unknown workflow"))
+        }
+
+        let parts = lines
+            .map(|(i, line)| line.parse().map_err(|e: AocError| e.at_line(i, &line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(System { workflows, parts }: &Self::Input) -> i32 {
+        parts.iter()
+            .filter(|part| process(part, workflows))
+            .map(|part| part.sum_value()).sum() // 319062
+    }
+
+    fn part2(System { workflows, .. }: &Self::Input) -> usize {
+        let mut accepted = CuboidSet::new();
+        let mut stack = vec![("in", all_ratings())];
+        while let Some((id, mut ratings)) = stack.pop() {
+            let rules = &workflows.get(id).unwrap().rules;
+            for rule in rules {
+                if let Some(matching) = ratings.intersection(&rule.requirements()) {
+                    match rule.destination().as_str() {
+                        "A" => accepted.insert(matching),
+                        "R" => {},
+                        destination => stack.push((destination, matching)),
+                    }
+                }
+                match rule.negated_requirements().and_then(|rest| ratings.intersection(&rest)) {
+                    Some(rest) => ratings = rest,
+                    None => break,
+                }
+            }
+        }
+        accepted.volume() // 118638369682135
+    }
+}
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+fn process(part: &Part, workflows: &HashMap<String, Workflow>) -> bool {
+    let mut workflow_id = "in";
+    loop {
+        match workflow_id {
+            "A" => return true,
+            "R" => return false,
+            _ => {
+                let workflow = &workflows[workflow_id];
+                workflow_id = workflow.process(part);
+            }
+        }
+    }
+}
+
+const MIN_INCLUSIVE: i32 = 1;
+const MAX_EXCLUSIVE: i32 = 4001;
+const FULL_RANGE: Range<i32> = MIN_INCLUSIVE..MAX_EXCLUSIVE;
+
+/// Ranges of ratings for each category, indexed by Category
+type Ratings = Cuboid<i32, 4>;
+
+fn all_ratings() -> Ratings {
+    Cuboid::new(array::from_fn(|_| FULL_RANGE))
+}
+
+impl Index<Category> for Ratings {
+    type Output = Range<i32>;
+
+    fn index(&self, category: Category) -> &Range<i32> {
+        &self.ranges[category.into_usize()]
+    }
+}
+
+impl IndexMut<Category> for Ratings {
+    fn index_mut(&mut self, category: Category) -> &mut Range<i32> {
+        &mut self.ranges[category.into_usize()]
+    }
+}
+
+struct Part {
+    ratings: EnumMap<Category, i32>
+}
+
+impl Part {
+    fn sum_value(&self) -> i32 {
+        self.ratings.values().sum()
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('{').and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| AocError::new("Expected '{x=..,m=..,a=..,s=..}'"))?;
+        let ratings = s.split(',').map(|rating| {
+            let (category, value) = rating.split_once('=').ok_or_else(|| AocError::invalid(rating, "Expected 'category=value'"))?;
+            Ok((category.parse()?, error::parse(value)?))
+        }).collect::<Result<_, AocError>>()?;
+        Ok(Part { ratings })
+    }
+    
+}
+
+struct Workflow {
+    id: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    /// Every workflow ends with a rule that always matches, checked when it's parsed
+    fn process(&self, part: &Part) -> &String {
+        self.rules.iter().find(|rule| rule.matches(part)).unwrap().destination()
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, rules) = s.split_once('{').ok_or_else(|| AocError::new("Expected 'id{rules}'"))?;
+        let id = String::from(id);
+        let rules = rules.strip_suffix('}').ok_or_else(|| AocError::invalid(rules, "Expected rules to end with '}'"))?;
+        let rules: Vec<Rule> = rules.split(',').map(str::parse).collect::<Result<_, _>>()?;
+        if !matches!(rules.last(), Some(Rule::Always(_))) {
+            return Err(AocError::new("Expected last rule to have no condition"))
+        }
+        Ok(Workflow { id, rules })
+    }
+}
+
+enum Rule {
+    Less(Category, i32, String),
+    Greater(Category, i32, String),
+    Always(String),
+}
+
+impl Rule {
+    fn matches(&self, part: &Part) -> bool {
+        match self {
+            Rule::Less(category, threshold, _) => part.ratings[*category] < *threshold,
+            Rule::Greater(category, threshold, _) => part.ratings[*category] > *threshold,
+            Rule::Always(_) => true,
+        }
+    }
+
+    fn destination(&self) -> &String {
+        match self {
+            Rule::Less(_, _, dest) | Rule::Greater(_, _, dest) | Rule::Always(dest) => dest,
+        }
+    }
+
+    fn requirements(&self) -> Ratings {
+        let mut ratings = all_ratings();
+        match self {
+            Rule::Less(category, threshold, _) => {
+                ratings[*category] = MIN_INCLUSIVE..*threshold;
+            },
+            Rule::Greater(category, threshold, _) => {
+                ratings[*category] = (threshold+1)..MAX_EXCLUSIVE;
+            }
+            Rule::Always(_) => {},
+        }
+        ratings
+    }
+
+    /// None if every rating matches
+    fn negated_requirements(&self) -> Option<Ratings> {
+        let mut ratings = all_ratings();
+        match self {
+            Rule::Less(category, threshold, _) => {
+                ratings[*category] = *threshold..MAX_EXCLUSIVE;
+            },
+            Rule::Greater(category, threshold, _) => {
+                ratings[*category] = MIN_INCLUSIVE..(threshold+1);
+            },
+            Rule::Always(_) => return None,
+        }
+        Some(ratings)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((condition, destination)) = s.split_once(':') else { return Ok(Rule::Always(String::from(s))) };
+        let mut chars = condition.chars();
+        let (Some(category), Some(comparison)) = (chars.next(), chars.next()) else {
+            return Err(AocError::invalid(condition, "Expected a condition like 'a<2006'"))
+        };
+        let category = Category::try_from(category)?;
+        let threshold = error::parse(chars.as_str())?;
+        match comparison {
+            '<' => Ok(Rule::Less(category, threshold, String::from(destination))),
+            '>' => Ok(Rule::Greater(category, threshold, String::from(destination))),
+            _ => Err(AocError::invalid(s, "Unrecognised rule"))
+        }
+    }
+}
+
+#[derive(Debug, Enum, Clone, Copy, PartialEq, Eq, Hash)]
+enum Category {
+    XtremelyCool, Musical, Aerodynamic, Shiny
+}
+
+impl TryFrom<char> for Category {
+    type Error = AocError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Category::XtremelyCool),
+            'm' => Ok(Category::Musical),
+            'a' => Ok(Category::Aerodynamic),
+            's' => Ok(Category::Shiny),
+            _ => Err(AocError::invalid(value, "Unrecognised category"))
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Category::try_from(c),
+            _ => Err(AocError::invalid(s, "Unrecognised category")),
+        }
+    }
+}
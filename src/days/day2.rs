@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use crate::{error::{self, AocError}, solution::Solution};
+
+use enum_map::{enum_map, Enum, EnumMap};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        crate::parse_lines(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        Part1::new().process(input) // 2512
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        Part2::new().process(input) // 67335
+    }
+}
+
+#[derive(Enum, Copy, Clone)]
+enum Color { Red, Green, Blue }
+
+impl FromStr for Color {
+    type Err = AocError;
+
+    fn from_str(str: &str) -> Result<Color,Self::Err> {
+        match str {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(AocError::invalid(str, "Unknown color"))
+        }
+    }
+}
+
+trait Part {
+    fn new() -> Self;
+    fn process(&self, games: &[Game]) -> u32;
+}
+
+struct Part1 {
+    available_cubes: EnumMap<Color, u32>
+}
+
+impl Part1 {
+    fn possible(&self, game: &Game) -> bool {
+        for (key, value) in game.cubes_seen {
+            if self.available_cubes[key] < value { return false }
+        }
+        true
+    }
+}
+
+impl Part for Part1 {
+    fn new() -> Part1 {
+        let available_cubes = enum_map! { Color::Red => 12, Color::Green => 13, Color::Blue => 14 };
+        Part1 { available_cubes }
+    }
+
+    fn process(&self, games: &[Game]) -> u32 {
+        games.iter().filter(|game| self.possible(game))
+        .map(|game| game.id)
+        .sum()
+    }
+}
+
+pub struct Game {
+    id: u32, cubes_seen: EnumMap<Color, u32>
+}
+
+impl FromStr for Game {
+    type Err = AocError;
+
+    fn from_str(str: &str) -> Result<Game,AocError> {
+        let (game, rounds) = Game::split_game_and_rounds(str)?;
+        let id = Game::parse_game_id(game)?;
+        let mut cubes_seen = enum_map! { Color::Red => 0, Color::Green => 0, Color::Blue => 0 };
+        for round in Game::split_rounds(rounds) {
+            for count_and_color in Game::split_count_and_colors(round) {
+                let (count, color) = count_and_color?;
+                if count > cubes_seen[color] { cubes_seen[color] = count }
+            }
+        }
+        Ok(Game { id, cubes_seen })
+    }
+}
+
+impl Game {
+    fn split_game_and_rounds(str: &str) -> Result<(&str, &str), AocError> {
+        str.split_once(':').ok_or_else(|| AocError::new("Expected 'Game N: rounds'"))
+    }
+
+    // Expects 'Game N'
+    fn parse_game_id(str: &str) -> Result<u32, AocError> {
+        let id = str.strip_prefix("Game ").ok_or_else(|| AocError::invalid(str, "Expected 'Game N'"))?;
+        error::parse(id)
+    }
+
+    fn split_rounds(rounds: &str) -> impl std::iter::Iterator<Item=&str> {
+        rounds.split(';').map(str::trim)
+    }
+
+    fn split_count_and_colors(round: &str) -> impl std::iter::Iterator<Item=Result<(u32, Color), AocError>> + '_ {
+        round.split(',').map(Game::parse_count_and_color)
+    }
+
+    // Expects 'n green'
+    fn parse_count_and_color(count_and_color: &str) -> Result<(u32, Color), AocError> {
+        let count_and_color = count_and_color.trim();
+        let (count, color) = count_and_color.split_once(' ')
+            .ok_or_else(|| AocError::invalid(count_and_color, "Expected 'n color'"))?;
+        Ok((error::parse(count)?, color.parse()?))
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    fn new() -> Self {
+        Part2 {}
+    }
+
+    fn process(&self, games: &[Game]) -> u32 {
+        games.iter().map(|game| game.cubes_seen)
+        .map(|cubes| cubes[Color::Red] * cubes[Color::Blue] * cubes[Color::Green])
+        .sum()
+    }
+}
@@ -1,192 +1,79 @@
-use std::{borrow::{Borrow, BorrowMut}, cell::RefCell, collections::{HashMap, HashSet, VecDeque}, fmt::{Debug, Display}, io::{Error, ErrorKind}, rc::Rc, str::FromStr, time::Instant};
+use std::{borrow::{Borrow, BorrowMut}, cell::RefCell, collections::{HashMap, HashSet, VecDeque}, fmt::{Debug, Display}, rc::Rc, str::FromStr};
 
-fn main() {
-    part1();
-    part2();
-    part2_alt();
-}
-
-fn part1() {
-    let mut machine = Machine::load();
-    let mut low_total = 0;
-    let mut high_total = 0;
-    for _ in 0..1000 {
-        let (low, high) = machine.press();
-        low_total += low;
-        high_total += high;
-    }
-    println!("Low {low_total}, High {high_total}, Product {}", low_total * high_total); // 763500168
-}
-
-fn part2() {
-    let mut machine = Machine::load();
-
-    machine.print_adjacency();
-
-    let graph = Machine::parse();
-
-    // try to simplify by walking back
-    // dh -> rx, want to see dh emit a low signal => everything feeding into dh emits high
-    let dh = get_rx_input(&graph);
-
-    // tr, xm, dr, nh -> dh, all Conjuntions.
-    // Want all of those to emit high => at least one node before each of them emits low
-    let l2s = get_l2_inputs(&graph, &dh);
-
-    let mut all_l3s = vec![];
-    for l2 in l2s {
-        let l3s = &l2.input_modules;
-        println!("{l3s:?} feeds into {}", l2.id);
-    
-        let mut l3s: Vec<_> = l3s.iter().map(|id| &graph[id]).collect();
-        println!("{} feeds into {l2}", l3s.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
-        if l3s.len() != 1 { panic!("Only expected one input to each l2 node"); }
-        all_l3s.append(&mut l3s);
-    }
-    
-    if all_l3s.len() != 4 || all_l3s.iter().any(|module| module.module_type != ModuleType::Conjunction) {
-        panic!("Expected 4 Conjunction inputs at level 3 (-> level 2 -> dh -> rx)");
-    }
-
-    // vc, tn, hd, jx
-    println!("L3 Conjunctions (all need to emit low): {}", all_l3s.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
-
-    let mut all_l4s = HashSet::new();
-    for l3 in all_l3s {
-        let l4s = &l3.input_modules;
-        println!("{l4s:?} feeds into {}", l3.id);
-    
-        let l4s: Vec<_> = l4s.iter().map(|id| &graph[id]).collect();
-        println!("{} feeds into {l3}", l4s.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
-
-        if l4s.iter().any(|l4| all_l4s.contains(l4) || l4.module_type != ModuleType::FlipFlop) {
-            panic!("Expected inputs to L3 conjunctions to all be disjoint flip-flops");
-        }
-        for l4 in l4s {
-            all_l4s.insert(l4);
-        }
-    }
+use crate::{error::AocError, math, solution::Solution};
 
-    // L4 flip flops all high -> L3 Conjunctions all low -> L2 conjunctions all high -> dh low -> rx
-    println!("L4 FlipFlops (all need to emit high): {}", all_l4s.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
+pub struct Day20;
 
-    // Record whenever those modules emit High
-    for l4 in &all_l4s {
-        (*machine.modules[&l4.id]).borrow_mut().get_base_module_mut().track_high_output = true;
-    }
+impl Solution for Day20 {
+    const DAY: u32 = 20;
 
-    let num_presses = 1_000_000;
+    type Input = HashMap<String, ParsedModule>;
+    type Answer1 = usize;
+    type Answer2 = u64;
 
-    let mut i = 0;
-    let start = Instant::now();
-    while i < num_presses {
-        i += 1;
-        machine.press();
-        if i % 1_000_000 == 0 { println!("Press {}m, t={}s", i/1_000_000, (Instant::now() - start).as_secs()); }
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Machine::parse(lines)
     }
-    println!("Complete after {i} presses");
 
-    for l4 in &all_l4s {
-        let module = (*machine.modules[&l4.id]).borrow();
-        let base_mod = module.get_base_module();
-        let same_cycle = base_mod.high_press_and_cycle.iter().map(|(_, cycle)| cycle).collect::<HashSet<_>>().len() == 1;
-        if !same_cycle { panic!("{} publishes High output on inconsistent cycle", base_mod.id); }
+    fn part1(graph: &Self::Input) -> usize {
+        let mut machine = Machine::load(graph);
+        let mut low_total = 0;
+        let mut high_total = 0;
+        for _ in 0..1000 {
+            let (low, high) = machine.press();
+            low_total += low;
+            high_total += high;
+        }
+        low_total * high_total // 763500168
     }
 
-    // Attempt to find cycle for each case. Gaps between High outputs might differ, so consider a window of
-    // up to OUTPUTS_TO_COLLECT / 4, and verify it against the rest of the gaps recorded.
-    let periods: Vec<u64> = all_l4s.iter().map(|l4| {
-        let module = (*machine.modules[&l4.id]).borrow();
-        let base_mod = module.get_base_module();
-        let deltas = base_mod.high_press_and_cycle.iter()
-            .map(|(press, _)| press)
-            .collect::<Vec<_>>()
-            .windows(2)
-            .map(|window| window[1] - window[0])
-            .collect::<Vec<_>>();
+    fn part2(graph: &Self::Input) -> u64 {
+        let mut machine = Machine::load(graph);
 
-        let deltas_period = (1..deltas.len()/4).filter(|deltas_period| {
-            let reference_deltas = &deltas[..*deltas_period];
+        // try to simplify by walking back
 
-            (1..deltas.len()/deltas_period).all(|window| (0..*deltas_period).all(|i| {
-                deltas[window*deltas_period + i] == reference_deltas[i]
-            }))
-        }).next().expect(&format!("Could not find period of gaps for {} after {} presses, deltas={:?}", base_mod.id, num_presses, deltas));
+        // dh -> rx, want to see dh emit a low signal => everything feeding into dh emits high
+        let dh = get_rx_input(graph);
 
-        let period = base_mod.high_press_and_cycle[deltas_period].0 - base_mod.high_press_and_cycle[0].0;
-
-        println!("{} has deltas period {} -> period {}", base_mod.id, deltas_period, period);
-
-        period as u64
-    }).collect();
-
-    println!("Periods: {periods:?}");
-    println!("LCM: {}", periods.iter().map(|n| *n as u64).reduce(|n, m| num::integer::lcm(n, m)).unwrap()); // 207652583562007
-}
+        // tr, xm, dr, nh -> dh, all Conjuntions.
+        // Want all of those to emit high => at least one node before each of them emits low
+        let l2s = get_l2_inputs(graph, dh);
 
-fn part2_alt() {
-    let mut machine = Machine::load();
-
-    // machine.print_adjacency();
-
-    let graph = Machine::parse();
-
-    // try to simplify by walking back
-
-     // dh -> rx, want to see dh emit a low signal => everything feeding into dh emits high
-    let dh = get_rx_input(&graph);
-    
-    // tr, xm, dr, nh -> dh, all Conjuntions.
-    // Want all of those to emit high => at least one node before each of them emits low
-    let l2s = get_l2_inputs(&graph, &dh);
+        // Record whenever those modules emit High
+        for l2 in &l2s {
+            (*machine.modules[&l2.id]).borrow_mut().get_base_module_mut().track_high_output = true;
+        }
 
-    // Record whenever those modules emit High
-    for l2 in &l2s {
-        (*machine.modules[&l2.id]).borrow_mut().get_base_module_mut().track_high_output = true;
-    }
+        while !machine.all_outputs_registered(1) {
+            machine.press();
+        }
 
-    let mut i = 0;
-    let start = Instant::now();
-    while !machine.all_outputs_registered(1) {
-        i += 1;
-        machine.press();
-        if i % 1_000_000 == 0 { println!("Press {}m, t={}s", i/1_000_000, (Instant::now() - start).as_secs()); }
+        let cycles: Vec<_> = l2s.iter().map(|l2| {
+            let module = (*machine.modules[&l2.id]).borrow();
+            let base_mod = module.get_base_module();
+            base_mod.high_press_and_cycle[0].0
+        }).collect();
+        math::lcm_all(cycles.iter().map(|n| *n as u64)).expect("Presses overflow") // 207652583562007
     }
-    println!("Complete after {i} presses");
-
-    let cycles: Vec<_> = l2s.iter().map(|l2| {
-        let module = (*machine.modules[&l2.id]).borrow();
-        let base_mod = module.get_base_module();
-        println!("{} - {:?}", base_mod.id, base_mod.high_press_and_cycle);
-        base_mod.high_press_and_cycle[0].0
-    }).collect();
-    println!("Cycles: {:?}", cycles);
-    println!("LCM: {}", cycles.iter().map(|n| *n as u64).reduce(|n, m| num::integer::lcm(n, m)).unwrap()); // 207652583562007
 }
 
 // Only one Conjunction before rx, care about when it emits low
 fn get_rx_input(graph: &HashMap<String, ParsedModule>) -> &ParsedModule {
-    let rx = &graph["rx"]; // want to see it receive low
-    let to_rx = &rx.input_modules;
-    println!("{to_rx:?} feeds into {}", rx.id);
-
-    let to_rx: Vec<_> = to_rx.iter().map(|id| &graph[id]).collect();
-    println!("{} feeds into {rx}", to_rx.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
+    let rx = graph.get("rx").expect("No rx module"); // want to see it receive low
+    let to_rx: Vec<_> = rx.input_modules.iter().map(|id| &graph[id]).collect();
 
     if to_rx.len() != 1 || to_rx[0].module_type != ModuleType::Conjunction {
         panic!("Expected one Conjunction input to rx");
     }
 
     // dh -> rx, want to see dh emit a low signal => everything feeding into dh emits high
-    let dh = to_rx[0];
-    dh
+    to_rx[0]
 }
 
 // tr, xm, dr, nh -> dh, all Conjuntions.
 // dh emits low => want all of these L2 nodes to emit high => at least one node before each of them emits low
 fn get_l2_inputs<'a>(graph: &'a HashMap<String, ParsedModule>, l1: &ParsedModule) -> Vec<&'a ParsedModule> {
     let l2s: Vec<_> = l1.input_modules.iter().map(|id| &graph[id]).collect();
-    println!("L2 {} feeds into {l1}", l2s.iter().map(|node| node.to_string()).collect::<Vec<_>>().join(", "));
 
     if l2s.len() != 4 || l2s.iter().any(|module| module.module_type != ModuleType::Conjunction) {
         panic!("Expected 4 Conjunction inputs to dh");
@@ -216,13 +103,19 @@ struct Machine {
 }
 
 impl Machine {
-    fn parse() -> HashMap<String, ParsedModule> {
-        let mut parsed_modules: HashMap<String, RefCell<ParsedModule>> = rust_aoc::read_input(20)
-            .map(|s| s.parse().unwrap())
-            .map(|module: ParsedModule| (module.id.clone(), RefCell::new(module)))
+    /// Modules that are only ever sent pulses, like rx, are added as receivers
+    fn parse(lines: impl Iterator<Item=String>) -> Result<HashMap<String, ParsedModule>, AocError> {
+        let mut parsed_modules: HashMap<String, RefCell<ParsedModule>> = crate::parse_lines::<ParsedModule>(lines)?.into_iter()
+            .map(|module| (module.id.clone(), RefCell::new(module)))
+            .collect();
+        if !parsed_modules.contains_key(BROADCASTER) { return Err(AocError::new("No broadcaster")) }
+        let receivers: HashSet<String> = parsed_modules.values()
+            .flat_map(|module| module.borrow().downstream_modules.clone())
+            .filter(|id| !parsed_modules.contains_key(id))
             .collect();
-        let output_module = ParsedModule::new(String::from("rx"), ModuleType::Receiver, vec![]);
-        parsed_modules.insert(output_module.id.clone(), RefCell::new(output_module));
+        for id in receivers {
+            parsed_modules.insert(id.clone(), RefCell::new(ParsedModule::new(id, ModuleType::Receiver, vec![])));
+        }
         // join up inputs for each node, needed to implement Conjunction
         for module in parsed_modules.values() {
             let module = module.borrow();
@@ -230,12 +123,10 @@ impl Machine {
                 parsed_modules[child].borrow_mut().add_input(module.id.clone());
             }
         }
-        parsed_modules.into_iter().map(|(id, cell)| (id.clone(), cell.into_inner())).collect()
+        Ok(parsed_modules.into_iter().map(|(id, cell)| (id, cell.into_inner())).collect())
     }
 
-    fn load() -> Machine {
-        let parsed_modules = Machine::parse();
-
+    fn load(parsed_modules: &HashMap<String, ParsedModule>) -> Machine {
         let modules: HashMap<String, ModuleRef> = parsed_modules.iter()
             .map(|(id, parsed)| (id.clone(), parsed.create_module()))
             .collect();
@@ -246,8 +137,8 @@ impl Machine {
             for to in &from.downstream_modules {
                 let to_ref = &modules[to];
                 let mut to_module = (**to_ref).borrow_mut();
-                from_module.get_base_module_mut().add_output(&to_ref);
-                to_module.get_base_module_mut().add_input(&from_ref);
+                from_module.get_base_module_mut().add_output(to_ref);
+                to_module.get_base_module_mut().add_input(from_ref);
             }
         }
 
@@ -292,27 +183,13 @@ impl Machine {
         self.modules.values().all(|module| {
             let module = (**module).borrow();
             let base_mod = module.get_base_module();
-            base_mod.track_high_output == false || base_mod.high_press_and_cycle.len() >= count
+            !base_mod.track_high_output || base_mod.high_press_and_cycle.len() >= count
         })
     }
-
-    fn print_adjacency(&self) {
-        for (id, module) in &self.modules {
-            let downstream: Vec<String> = (**module).borrow().get_base_module().downstream_modules.iter()
-                .map(|module| (**module).borrow().get_base_module().id.clone())
-                .collect();
-            if downstream.is_empty() {
-                println!("{id}");
-            } else {
-                let downstream = downstream.join(";");
-                println!("{id};{downstream}");
-            }
-        }
-    }
 }
 
 #[derive(Clone)]
-struct ParsedModule {
+pub struct ParsedModule {
     id: String,
     module_type: ModuleType,
     input_modules: Vec<String>,
@@ -360,19 +237,19 @@ impl Display for ParsedModule {
 }
 
 impl FromStr for ParsedModule {
-    type Err = Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (id, downstream_modules) = s.split_once(" -> ").unwrap();
+        let (id, downstream_modules) = s.split_once(" -> ").ok_or_else(|| AocError::new("Expected 'module -> outputs'"))?;
         let downstream_modules = downstream_modules.split(", ").map(String::from).collect();
         if id == "broadcaster" {
             Ok(ParsedModule::new(String::from(id), ModuleType::Broadcast, downstream_modules))
-        } else if id.starts_with('%') {
-            Ok(ParsedModule::new(String::from(&id[1..]), ModuleType::FlipFlop, downstream_modules))
-        } else if id.starts_with('&') {
-            Ok(ParsedModule::new(String::from(&id[1..]), ModuleType::Conjunction, downstream_modules))
+        } else if let Some(id) = id.strip_prefix('%') {
+            Ok(ParsedModule::new(String::from(id), ModuleType::FlipFlop, downstream_modules))
+        } else if let Some(id) = id.strip_prefix('&') {
+            Ok(ParsedModule::new(String::from(id), ModuleType::Conjunction, downstream_modules))
         } else {
-            Err(Error::new(ErrorKind::InvalidInput, format!("Unrecognised ID {id}")))
+            Err(AocError::invalid(id, "Unrecognised ID"))
         }
     }
 }
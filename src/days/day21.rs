@@ -1,19 +1,33 @@
 use std::{collections::HashSet, fmt::Debug};
 
-use rust_aoc::{grid::Grid, point::Point};
+use crate::{error::AocError, grid::Grid, point::Point, solution::Solution, BreadthFirstSearch};
 
-fn main() {
-    let (start, grid) = load_grid();
+pub struct Day21;
 
-    part1(&start, &grid);
-    part2(&start, &grid);
-}
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = (Point, Grid<Cell>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        load_grid(lines)
+    }
+
+    fn part1((start, grid): &Self::Input) -> usize {
+        reachable_cells(start, grid, 64) // 3666
+    }
 
-fn part1(start: &Point, grid: &Grid<Cell>) {
-    println!("Visited {}", reachable_cells(start, grid, 64)); // 3666
+    fn part2((start, grid): &Self::Input) -> usize {
+        reachable_cells_infinite(start, grid, 26501365) // 609298746763952
+    }
 }
 
-fn part2(start: &Point, grid: &Grid<Cell>) {
+/// Cells reachable in exactly total_steps steps in the garden repeated infinitely in every direction,
+/// worked out from searches of single copies of the garden. Only works for inputs shaped like the real one,
+/// and numbers of steps that reach the middle of the edge of an even number of gardens from the start.
+fn reachable_cells_infinite(start: &Point, grid: &Grid<Cell>, total_steps: usize) -> usize {
     // Verify that there is a trivial shortest path to enter any grid:
     // - there are straight lines up/down/left/right from the start to the border
     // - having reaching the border, you can freely move along the edge to reach the right column/row to enter from
@@ -28,13 +42,10 @@ fn part2(start: &Point, grid: &Grid<Cell>) {
             && grid[&Point { x: 0, y }] == Cell::Garden
             && grid[&Point { x: grid.width - 1, y }] == Cell::Garden
         );
-    if shortest_paths_trivial {
-        println!("Trivial path from start to entry of any grid");
-    } else {
+    if !shortest_paths_trivial {
         panic!("Non-trivial path to entry of each grid, can't simplify problem");
     }
 
-    println!("Grid shape is {} by {}, start={}", grid.width, grid.height, start);
     if grid.width != grid.height {
         panic!("Non-square grid, non-trivial to reason about traversing");
     }
@@ -43,12 +54,11 @@ fn part2(start: &Point, grid: &Grid<Cell>) {
     assert!(grid_length % 2 == 1);
     let midpoint = grid_length / 2;
 
-    let total_steps = 26501365usize;
     let whole_grids = total_steps / (grid_length as usize); // 202300
-    let steps_into_last_grid = total_steps % (grid_length as usize); // 65
-    println!("Total steps is {total_steps} = {whole_grids} whole grids ({grid_length}) + {steps_into_last_grid} steps from last grid");
-    
-    println!("Start from {start}, which should be the center");
+    // + total_steps % grid_length = 65 steps from last grid
+    assert!(total_steps % grid_length as usize == midpoint as usize);
+
+    // Start should be the center
     assert!(*start == Point { x: midpoint, y: midpoint });
 
     // 1. (whole_grids - 2) grid steps out from center, leaves us in the center of various grids, 131 + 131 + 65 steps left
@@ -91,7 +101,7 @@ fn part2(start: &Point, grid: &Grid<Cell>) {
     //    .
     // n whole grids + partial -> (n-1) filled grids out from center + partial border -> 1 + 4*(n-1 + n-2 + n-3 + ... 1)
     // But need to split into even vs odd grids.
-    assert!(whole_grids % 2 == 0); // simplify, can assume n (whole_grids) even
+    assert!(whole_grids.is_multiple_of(2)); // simplify, can assume n (whole_grids) even
     assert!(grid_length % 2 == 1); // grid length is odd, so flips between grids
     assert!(total_steps % 2 == 1); // total steps odd, so center grid is odd, and then 1st grid of each quarter even
     // n whole grids + partial -> n-1 filled grids out from center -> center grid + 4 trianges of length (n-1)
@@ -107,19 +117,14 @@ fn part2(start: &Point, grid: &Grid<Cell>) {
     
     let filled_count = filled_odd_grids * odd_filled_grid_poitions + filled_even_grids * even_filled_grid_positions;
 
-    println!("Count from {filled_odd_grids} odd + {filled_even_grids} even filled grids: {filled_count}");
-
     // 1 point in each direction
     let point_count: usize = point_counts.iter().sum();
-    println!("Points total: {point_count}");
 
     // for n whole grids, point cells are n diagonal steps apart => n 'outer' corner grids, n-1 'inner' corner grids
     let outer_corner_total = outer_corners_positions * whole_grids;
     let inner_corner_total = inner_corners_positions * (whole_grids - 1);
-    println!("Outer corners total {outer_corner_total}. Inner corner total {inner_corner_total}");
 
-    let overall_total = filled_count + point_count + outer_corner_total + inner_corner_total;
-    println!("Overall total: {overall_total}"); // 609298746763952
+    filled_count + point_count + outer_corner_total + inner_corner_total
 }
 
 fn corner_points(grid: &Grid<Cell>) -> [Point; 4] {
@@ -133,49 +138,123 @@ fn corner_points(grid: &Grid<Cell>) -> [Point; 4] {
 }
 
 fn reachable_cells(start: &Point, grid: &Grid<Cell>, steps: i64) -> usize {
-    let mut positions = HashSet::new();
-    positions.insert(*start);
-    for _ in 0..steps {
-        positions = positions.iter().flat_map(|p| get_neighbours(p, &grid)).collect();
-    }
-    positions.len()
+    reachable_cells_at_each_point(start, grid, vec![steps])[0]
 }
 
+/// Having reached a cell, it's possible to step away and back again, and the grid alternates between
+/// cells an odd and even number of steps away. So the cells reachable in exactly n steps are those
+/// with a shortest path of at most n steps, and the same parity as n.
 fn reachable_cells_at_each_point(start: &Point, grid: &Grid<Cell>, steps: Vec<i64>) -> Vec<usize> {
     if !steps.windows(2).all(|values| values[0] < values[1]) { panic!("Out of order steps counts {steps:?}"); }
 
-    let mut positions = HashSet::new();
-    positions.insert(*start);
-    let mut previous = 0;
+    let max_steps = *steps.last().unwrap() as usize;
+    let layer_sizes: Vec<usize> = GardenSearch { grid, visited: HashSet::new() }.layers(*start)
+        .take(max_steps + 1)
+        .map(|layer| layer.len())
+        .collect();
 
-    steps.into_iter().map(|end| {
-        for _ in previous..end {
-            positions = positions.iter().flat_map(|p| get_neighbours(p, &grid)).collect();
-        }
-        previous = end;
-        positions.len()
-    }).collect()
+    steps.into_iter().map(|end| reachable_from_layers(&layer_sizes, end)).collect()
 }
 
-fn load_grid() -> (Point, Grid<Cell>) {
-    let mut start = Point { x: -1, y: -1 };
-    let grid = Grid::parse(rust_aoc::read_input(21), |c| c).map(|p, c| {
-        match c {
-            '.' => Cell::Garden,
-            '#' => Cell::Rock,
-            'S' => {
-                start = p;
-                Cell::Garden
-            },
-            _ => panic!("Unexpected cell {c}"),
-        }
-    });
-    (start, grid)
+fn reachable_from_layers(layer_sizes: &[usize], end: i64) -> usize {
+    layer_sizes.iter().enumerate()
+        .filter(|(distance, _)| *distance as i64 <= end && (*distance as i64) % 2 == end % 2)
+        .map(|(_, count)| count)
+        .sum()
+}
+
+struct GardenSearch<'a> {
+    grid: &'a Grid<Cell>,
+    visited: HashSet<Point>,
+}
+
+impl BreadthFirstSearch for GardenSearch<'_> {
+    type Node = Point;
+
+    fn mark(&mut self, p: &Point) -> bool {
+        self.visited.insert(*p)
+    }
+
+    fn neighbours(&self, p: &Point) -> Vec<Point> {
+        get_neighbours(p, self.grid)
+    }
+}
+
+fn load_grid(lines: impl Iterator<Item=String>) -> Result<(Point, Grid<Cell>), AocError> {
+    let grid = Grid::try_parse_lines(lines, |c| match c {
+        '.' | '#' | 'S' => Ok(c),
+        _ => Err(AocError::invalid(c, "Unexpected cell")),
+    })?;
+    let (start, _) = grid.enumerate().find(|(_, &c)| c == 'S').ok_or_else(|| AocError::new("No start"))?;
+    let grid = grid.map(|_, c| if c == '#' { Cell::Rock } else { Cell::Garden });
+    Ok((start, grid))
 }
 
 fn get_neighbours(p: &Point, grid: &Grid<Cell>) -> Vec<Point> {
-    p.orthogonal_neighbours().into_iter().filter(|p| matches!(grid.get(p), Some(Cell::Garden))).collect()
+    grid.neighbours(p).filter(|n| grid[n] == Cell::Garden).collect()
 }
 
 #[derive(Debug, Eq, PartialEq)]
-enum Cell { Garden, Rock }
+pub enum Cell { Garden, Rock }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiled_grid::TiledGrid;
+
+    /// As reachable_cells, walking straight out of the garden into the copies of it all around.
+    /// Slow for large numbers of steps, as every reachable cell is visited.
+    fn reachable_cells_tiled(start: &Point, grid: &Grid<Cell>, steps: i64) -> usize {
+        let layer_sizes: Vec<usize> = TiledGardenSearch { grid: TiledGrid::new(grid), visited: HashSet::new() }.layers(*start)
+            .take(steps as usize + 1)
+            .map(|layer| layer.len())
+            .collect();
+        reachable_from_layers(&layer_sizes, steps)
+    }
+
+    struct TiledGardenSearch<'a> {
+        grid: TiledGrid<'a, Cell>,
+        visited: HashSet<Point>,
+    }
+
+    impl BreadthFirstSearch for TiledGardenSearch<'_> {
+        type Node = Point;
+
+        fn mark(&mut self, p: &Point) -> bool {
+            self.visited.insert(*p)
+        }
+
+        fn neighbours(&self, p: &Point) -> Vec<Point> {
+            self.grid.neighbours(p).filter(|n| self.grid[n] == Cell::Garden).collect()
+        }
+    }
+
+    /// Shaped like the real input, with clear paths along the edges and out from the start
+    const GARDEN: &str = "\
+...........
+.#.....##..
+.##......#.
+.......#...
+..#........
+.....S.....
+.......###.
+...........
+.........#.
+.......#...
+...........";
+
+    fn garden() -> (Point, Grid<Cell>) {
+        Day21::parse(GARDEN.lines().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn analytical_count_matches_tiled_search() {
+        let (start, grid) = garden();
+        // the fewest steps the analytical count handles, and a few more gardens out
+        for gardens in [2, 4, 6] {
+            let steps = gardens * grid.width as usize + (grid.width / 2) as usize;
+            assert_eq!(reachable_cells_infinite(&start, &grid, steps), reachable_cells_tiled(&start, &grid, steps as i64),
+                "Analytical count is wrong after {steps} steps");
+        }
+    }
+}
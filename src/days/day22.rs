@@ -0,0 +1,124 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{cuboid::Cuboid, dominators::Dominators, error::AocError, point3::{Axis, Point3}, solution::Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input = Vec<Brick>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut bricks: Vec<Brick> = crate::parse_lines(lines)?;
+        // sort by (start) z coordinate, then just need to look at earlier bricks to simulate each brick falling, and never revisit
+        bricks.sort_by_key(|p| p.start.z);
+        Ok(bricks)
+    }
+
+    fn part1(bricks: &Self::Input) -> usize {
+        let supporters_of_bricks = find_supporters(bricks);
+
+        let lone_supporters: HashSet<_> = supporters_of_bricks.iter()
+            .filter(|set| set.len() == 1)
+            .flat_map(|set| set.iter()).collect();
+
+        // Of all bricks, the lone supporters are the only ones supporting some other brick, the rest could be removed
+        bricks.len() - lone_supporters.len() // 395
+    }
+
+    fn part2(bricks: &Self::Input) -> usize {
+        let supporters_of_bricks = find_supporters(bricks);
+
+        // Working out how many other bricks fall if one brick is removed is the problem of Dominators in graph theory.
+        // With the ground as the root, and edges from each brick to the bricks it supports, a brick falls when
+        // brick i is removed exactly when i dominates it, so the bricks that fall are i's subtree in the dominator tree.
+        let graph = SupportGraph::new(&supporters_of_bricks);
+        let tree = graph.dominator_tree(&graph.ground);
+        (0..bricks.len()).map(|i| tree.subtree_size(&i) - 1).sum() // 64714
+    }
+}
+
+/// Simulates the bricks falling, then for each brick, works out which ones it is supported by.
+/// Bricks must already be sorted by height.
+fn find_supporters(bricks: &[Brick]) -> Vec<HashSet<usize>> {
+    // exploiting the fact all bricks are axis-aligned, they're cuboids that intersect
+    // if their start-end overlap in all 3 dimensions.
+    let mut bricks: Vec<Cuboid<i64, 3>> = bricks.iter().map(Brick::cuboid).collect();
+    let down = [0, 0, -1];
+
+    for i in 0..bricks.len() {
+        let mut brick = bricks[i].clone();
+        while brick.ranges[Axis::Z as usize].start > 1 {
+            let new_brick = brick.translate(down);
+            let collides = (0..i).rev().any(|j| bricks[j].intersects(&new_brick));
+            if collides {
+                break;
+            } else {
+                brick = new_brick;
+            }
+        }
+        bricks[i] = brick;
+    }
+
+    bricks.iter().enumerate().map(|(i, brick)| {
+        let moved_brick = brick.translate(down);
+        (0..i).filter(|&j| bricks[j].intersects(&moved_brick)).collect()
+    }).collect()
+}
+
+struct SupportGraph {
+    /// Bricks supported by each brick, with the ground as an extra node after all the bricks
+    supported: Vec<Vec<usize>>,
+    ground: usize,
+}
+
+impl SupportGraph {
+    fn new(supporters_of_bricks: &[HashSet<usize>]) -> Self {
+        let ground = supporters_of_bricks.len();
+        let mut supported = vec![Vec::new(); ground + 1];
+        for (i, supporters) in supporters_of_bricks.iter().enumerate() {
+            if supporters.is_empty() { supported[ground].push(i) }
+            for j in supporters {
+                supported[*j].push(i);
+            }
+        }
+        SupportGraph { supported, ground }
+    }
+}
+
+impl Dominators for SupportGraph {
+    type Node = usize;
+
+    fn get_edges(&self, brick: &usize) -> Vec<&usize> {
+        self.supported[*brick].iter().collect()
+    }
+}
+
+// Always a straight line i.e. only 1 coordinate differs.
+// To simplify handling, always sort points lexicographically so that start < end
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Brick {
+    start: Point3, // inclusive at both ends
+    end: Point3,
+}
+
+impl Brick {
+    fn cuboid(&self) -> Cuboid<i64, 3> {
+        let Brick { start, end } = self;
+        Cuboid::from_corners([start.x, start.y, start.z], [end.x, end.y, end.z])
+    }
+}
+
+impl FromStr for Brick {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s.split_once('~').ok_or_else(|| AocError::new("Expected 'x,y,z~x,y,z'"))?;
+        let (first, second): (Point3, Point3) = (first.parse()?, second.parse()?);
+        let (start, end) = if first < second { (first, second) } else { (second, first) };
+        Ok(Brick { start, end })
+    }
+}
@@ -1,34 +1,46 @@
-use std::{collections::{HashMap, HashSet}, io::{Error, ErrorKind}};
+use std::collections::{HashMap, HashSet};
 
-use rust_aoc::{direction::Direction, grid::Grid, point::Point};
+use crate::{direction::Direction, error::AocError, grid::{Grid, GridCell}, point::Point, solution::Solution};
 
+pub struct Day23;
 
-fn main() {
-    let grid: Grid<Cell> = Grid::parse(rust_aoc::read_input(23), |c| c.try_into().unwrap());
+impl Solution for Day23 {
+    const DAY: u32 = 23;
 
-    let (start, _) = grid.row(0).iter().enumerate().filter(|(_, &cell)| cell == Cell::Empty).next().unwrap();
+    type Input = Grid<Cell>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Grid::try_parse_lines(lines, Cell::try_from)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        longest_path(grid) // 1966
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        // Part 2, 'slippy' cells no longer slippy, more options available
+        let grid = grid.clone().map(|_, cell| match cell {
+            Cell::West | Cell::East | Cell::North | Cell::South => Cell::Empty,
+            _ => cell
+        });
+        longest_path(&grid) // 6286
+    }
+}
+
+fn longest_path(grid: &Grid<Cell>) -> usize {
+    let (start, _) = grid.row(0).iter().enumerate().find(|(_, &cell)| cell == Cell::Empty).unwrap();
     let start = Point { x: start as i64, y: 0 };
     let (end, _) = grid.row((grid.height - 1) as usize).iter().enumerate()
-        .filter(|(_, &cell)| cell == Cell::Empty).next().unwrap();
+        .find(|(_, &cell)| cell == Cell::Empty).unwrap();
     let end = Point { x: end as i64, y: grid.height - 1 };
 
-    println!("Start {start}, End {end}");
-
     // Abstract grid into a graph, where each node is a point in the grid that is one of:
     // 1) the start, 2) the end, 3) a non-trivial node with 3 possible next steps (1 of which is going back the same way so ignored)
 
-    let graph = Graph::new(&start, &end, &grid);
-    println!("Grid size {} x {} = {}; Graph size {}", grid.width, grid.height, grid.width * grid.height, graph.nodes.len());
-    println!("Part 1 Longest path: {}", graph.longest_path()); // 1966
-
-    // Part 2, 'slippy' cells no longer slippy, more options available
-    let grid = grid.map(|_, cell| match cell {
-        Cell::West | Cell::East | Cell::North | Cell::South => Cell::Empty,
-        _ => cell
-    });
-    let graph = Graph::new(&start, &end, &grid);
-    println!("Grid size {} x {} = {}; Graph size {}", grid.width, grid.height, grid.width * grid.height, graph.nodes.len());
-    println!("Part 2 Longest path: {}", graph.longest_path()); // 6286
+    let graph = Graph::new(&start, &end, grid);
+    graph.longest_path()
 }
 
 #[derive(Debug)]
@@ -62,7 +74,7 @@ impl Graph {
                 // mark as visited, put back on stack to 'leave' later, and explore children
                 visited.insert(node);
                 to_explore.push((node, distance));
-                for (neighbour, cost) in self.nodes[&node].iter().filter(|(neighbour, _)| !visited.contains(&neighbour)) {
+                for (neighbour, cost) in self.nodes[&node].iter().filter(|(neighbour, _)| !visited.contains(*neighbour)) {
                     to_explore.push((*neighbour, distance + cost));
                 }
             }
@@ -105,7 +117,7 @@ impl Graph {
         while !nodes.contains(&current) {
             steps += 1;
             let neighbours: Vec<_> = neighbours(&current, grid).into_iter().filter(|&p| p != previous).collect();
-            if neighbours.len() == 0 { return None }
+            if neighbours.is_empty() { return None }
             let next = neighbours[0];
             previous = current;
             current = next; 
@@ -124,9 +136,7 @@ fn neighbours(p: &Point, grid: &Grid<Cell>) -> Vec<Point> {
         Cell::North => vec![Direction::North],
         Cell::South => vec![Direction::South],
     };
-    candidates.into_iter().map(|dir| neighbour_in_direction(p, dir, grid))
-        .filter(Option::is_some)
-        .map(Option::unwrap)
+    candidates.into_iter().filter_map(|dir| neighbour_in_direction(p, dir, grid))
         .collect()
 }
 
@@ -152,7 +162,7 @@ fn surrounding_walls(p: &Point, grid: &Grid<Cell>) -> usize {
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-enum Cell {
+pub enum Cell {
     Empty,
     Wall,
     West,
@@ -161,8 +171,21 @@ enum Cell {
     South,
 }
 
+impl GridCell for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::West => '<',
+            Cell::East => '>',
+            Cell::North => '^',
+            Cell::South => 'v',
+        }
+    }
+}
+
 impl TryFrom<char> for Cell {
-    type Error = Error;
+    type Error = AocError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         let value = match value {
@@ -172,7 +195,7 @@ impl TryFrom<char> for Cell {
             '>' => Cell::East,
             '^' => Cell::North,
             'v' => Cell::South,
-            _ => return Err(Error::new(ErrorKind::InvalidInput, format!("Unknown cell {value}")))
+            _ => return Err(AocError::invalid(value, "Unknown cell"))
         };
         Ok(value)
     }
@@ -1,29 +1,39 @@
-use std::{ io::Error, str::FromStr};
+use std::str::FromStr;
 
 use nalgebra::{Matrix2, Vector2, Vector3};
 use num::abs;
-use rust_aoc::{point::Point, point3::Point3};
+use crate::{error::AocError, point::Point, point3::Point3, solution::Solution};
 
+pub struct Day24;
 
-fn main() {
-    let stones: Vec<Stone> = rust_aoc::read_input(24).map(|s| s.parse().unwrap()).collect();
-    part1(&stones);
-    part2(&stones);
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Vec<Stone>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        crate::parse_lines(lines)
+    }
+
+    fn part1(stones: &Self::Input) -> usize {
+        part1(stones) // 16939
+    }
+
+    fn part2(stones: &Self::Input) -> i64 {
+        part2(stones) // 931193307668256
+    }
 }
 
-fn part1(stones: &Vec<Stone>) {
+fn part1(stones: &[Stone]) -> usize {
     let stones: Vec<_> = stones.iter().map(|stone| stone.project()).collect();
-    let num_stones = stones.len();
-    let total: usize = (0..num_stones).map(|i| {
-        let stone1 = &stones[i];
-        (0..i).filter(|&j| intersect_in_region(stone1, &stones[j])).count()
-    }).sum();
-    println!("Total intersections: {total}"); // 16939
+    stones.iter().enumerate().map(|(i, stone1)| {
+        stones[..i].iter().filter(|stone2| intersect_in_region(stone1, stone2)).count()
+    }).sum()
 }
 
-fn part2(stones: &Vec<Stone>) {
-    println!("Part 2");
-
+fn part2(stones: &[Stone]) -> i64 {
     let num_stones = stones.len();
 
     let parallel_pairs: usize = (0..num_stones).map(|i| {
@@ -35,24 +45,19 @@ fn part2(stones: &Vec<Stone>) {
             is_parallel(v1, v2)
         }).count()
     }).sum();
-    
-    println!("Number of parallel pairs: {parallel_pairs}");
+
     assert_eq!(0, parallel_pairs, "If lines were parallel, we could simplify the problem substantially");
 
     // Complex calculation, so hard to guard against numeric precision issues.
     // Instead, we just try various triplets of stones until we find a combination that appears to have suitably small error.
     // (we could exploit knowning the intersections only happen at integer times, to do some error correction part way through,
     //  but the exercise doesn't actually say this is guaranteed)
-    let (attempt, origin) = (0..num_stones/3)
+    let origin = (0..num_stones/3)
         .map(|i| part2_find_origin(&stones[3*i], &stones[3*i + 1], &stones[3*i + 2]))
-        .enumerate()
-        .filter(|(_, p)| is_integer_point(p))
-        .next()
+        .find(is_integer_point)
         .expect("Didn't find an integer point solution, suggests need to do something about arithmetic errors");
-    let attempt = attempt + 1;
     let origin = Vector3::new(origin.x.round(), origin.y.round(), origin.z.round());
-    println!("Found origin on attempt {attempt}: {origin}");
-    println!("Sum of coordinates: {}", origin.x + origin.y + origin.z); // 931193307668256
+    (origin.x + origin.y + origin.z) as i64
 }
 
 /*
@@ -113,8 +118,7 @@ fn part2_find_origin(s1: &Stone, s2: &Stone, s3: &Stone) -> Vector3<f64> {
     let q3 = p3 + t3 * v3;
 
     let v = (q3 - q2)/(t3 - t2);
-    let p = q3 - t3*v;
-    p
+    q3 - t3*v
 }
 
 fn is_integer_point(p: &Vector3<f64>) -> bool {
@@ -236,7 +240,7 @@ fn within_region(p: Vector2<f64>) -> bool {
 }
 
 fn within_range(f: f64) -> bool {
-    f >= MIN && f <= MAX
+    (MIN..=MAX).contains(&f)
 }
 
 struct Stone2 {
@@ -245,7 +249,7 @@ struct Stone2 {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Stone {
+pub struct Stone {
     pos: Point3,
     v: Point3,
 }
@@ -262,12 +266,13 @@ impl Stone {
 }
 
 impl FromStr for Stone {
-    type Err = Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (pos, v) = rust_aoc::split_in_two(s, '@');
-        let (pos, v) = (pos.parse().unwrap(), v.parse().unwrap());
-        Ok(Stone { pos, v})
+        let (pos, v) = s.split_once('@').ok_or_else(|| AocError::invalid(s, "Expected 'position @ velocity'"))?;
+        // Point3 already reports its own invalid text
+        let (pos, v) = (pos.parse()?, v.parse()?);
+        Ok(Stone { pos, v })
     }
 }
 
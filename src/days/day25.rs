@@ -0,0 +1,113 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{error::AocError, graph::Graph, solution::Solution};
+
+pub struct Day25;
+
+/// Day 25 only has one puzzle, so part 2 cross-checks the part 1 answer using Karger-Stein instead of Stoer-Wagner.
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Input = Graph<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Ok(parse_graph(lines))
+    }
+
+    // Version 1: ~300ms
+    // Version 2: Separate HashSet of String node IDs, and edges instead just store Rc<String> references: No change
+    // Version 3: Index of ID -> details of node, reduces time to ~230ms
+    fn part1(graph: &Self::Input) -> usize {
+        let cut = graph.find_cut_stoer_wagner(CUTS_ALLOWED).expect("Didn't find a suitable cut");
+        let [first, second] = cut.partitions;
+        first.len() * second.len() // 583632
+    }
+
+    // Each run of Karger-Stein only finds the cut with some probability, so keep trying until it does.
+    fn part2(graph: &Self::Input) -> usize {
+        let mut rng = StdRng::seed_from_u64(KARGER_STEIN_SEED);
+        loop {
+            let cut = graph.karger_stein(&mut rng, CUTS_ALLOWED).expect("Graph has fewer than 2 nodes");
+            if cut.weight <= CUTS_ALLOWED {
+                let [first, second] = cut.partitions;
+                return first.len() * second.len() // 583632
+            }
+        }
+    }
+}
+
+const CUTS_ALLOWED: usize = 3;
+// Any seed will do, fixed so that part 2 takes the same time on each run
+const KARGER_STEIN_SEED: u64 = 25;
+
+fn parse_graph(lines: impl Iterator<Item=String>) -> Graph<String> {
+    let mut graph = Graph::new();
+    for line in lines {
+        let (node, neighbours) = crate::split_in_two(&line, ':');
+        let (node, neighbours) = (node.trim(), neighbours.trim());
+        for neighbour in neighbours.split_ascii_whitespace() {
+            graph.add_edge(String::from(node), String::from(neighbour), 1);
+        }
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::seq::SliceRandom;
+
+    use crate::graph::Cut;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    /// The minimum s-t cuts between random pairs of nodes
+    fn sample_cuts_by_max_flow(graph: &Graph<String>, seed: u64, samples: usize) -> Vec<Cut<String>> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let nodes: Vec<&String> = graph.nodes().collect();
+        (0..samples)
+            .filter_map(|_| {
+                let pair: Vec<_> = nodes.choose_multiple(&mut rng, 2).collect();
+                let [s, t] = pair[..] else { return None };
+                graph.min_st_cut(s, t)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        let graph = Day25::parse(EXAMPLE.lines().map(String::from)).unwrap();
+        assert_eq!(Day25::part1(&graph), 54);
+        assert_eq!(Day25::part2(&graph), 54);
+    }
+
+    /// No sampled pair of components is separated by fewer than CUTS_ALLOWED wires, and pairs on opposite
+    /// sides of the three wires are separated by exactly that many
+    #[test]
+    fn max_flow_agrees_with_cuts_allowed() {
+        let graph = Day25::parse(EXAMPLE.lines().map(String::from)).unwrap();
+        let cuts = sample_cuts_by_max_flow(&graph, 1, 20);
+        assert_eq!(cuts.len(), 20);
+        assert!(cuts.iter().all(|cut| cut.weight >= CUTS_ALLOWED));
+        let cut = cuts.into_iter().min_by_key(|cut| cut.weight).unwrap();
+        assert_eq!(cut.weight, CUTS_ALLOWED);
+        let [first, second] = cut.partitions;
+        assert_eq!(first.len() * second.len(), Day25::part1(&graph));
+    }
+}
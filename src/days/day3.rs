@@ -1,63 +1,72 @@
 use std::collections::HashSet;
 
-use rust_aoc::point::Point;
+use crate::{error::AocError, grid::Grid, point::Point, solution::Solution};
 
-fn main() {
-    let input = Input::load();
-    Part1::process(&input);
-    Part2::process(&input);
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        Ok(Input::load(lines))
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        Part1::process(input) // 531561
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        Part2::process(input) // 83279367
+    }
 }
 
 trait Part {
-    fn process(input: &Input);
+    fn process(input: &Input) -> u32;
 }
 
-struct Input {
-    symbol_grid: HashSet<Point>,
+pub struct Input {
+    grid: Grid<Cell>,
     gears: HashSet<Point>,
     numbers: HashSet<GridNumber>
 }
 
 impl Input {
-    fn load() -> Input {
-        let mut symbol_grid = HashSet::new();
+    fn load(lines: impl Iterator<Item=String>) -> Input {
+        let lines: Vec<String> = lines.collect();
         let mut gears = HashSet::new();
         let mut numbers = HashSet::new();
 
-        rust_aoc::read_input(3)
-            .enumerate()
-            .for_each(|(i, line)| LineParser::parse(&line, i, &mut symbol_grid, &mut gears, &mut numbers));
+        lines.iter().enumerate()
+            .for_each(|(i, line)| LineParser::parse(line, i, &mut gears, &mut numbers));
 
-        Input { symbol_grid, gears, numbers }
+        Input { grid: Grid::parse_lines(lines.into_iter(), Cell::parse), gears, numbers }
     }
 }
 
 struct Part1 {}
 
 impl Part for Part1 {
-    fn process(input: &Input) {
-        let total: u32 = input.numbers.iter()
-            .filter(|num| num.is_part(&input.symbol_grid))
+    fn process(input: &Input) -> u32 {
+        input.numbers.iter()
+            .filter(|num| num.is_part(&input.grid))
             .map(|num| num.value)
-            .sum();
-
-        // 1. 531561
-        println!("Total: {total}");
+            .sum()
     }
 }
 
 struct Part2;
 
 impl Part for Part2 {
-    fn process(input: &Input) {
-        let total: u32 = input.gears.iter()
+    fn process(input: &Input) -> u32 {
+        input.gears.iter()
             .map(|gear| input.numbers.iter().filter(|num| num.touches(gear)).collect::<Vec<_>>())
             .filter(|nums| nums.len() == 2)
             .map(|nums| nums[0].value * nums[1].value)
-            .sum();
-
-        // 2. 83279367
-        println!("Total: {total}");
+            .sum()
     }
 }
 
@@ -65,7 +74,6 @@ struct LineParser<'a> {
     y: i64,
     current_num: u32,
     current_num_start: Point,
-    symbol_grid: &'a mut HashSet<Point>,
     gears: &'a mut HashSet<Point>, 
     numbers: &'a mut HashSet<GridNumber>
 }
@@ -74,7 +82,6 @@ impl LineParser<'_> {
     fn parse(
         line: &str,
         y: usize, 
-        symbol_grid: &mut HashSet<Point>,
         gears: &mut HashSet<Point>, 
         numbers: &mut HashSet<GridNumber>
     ) {
@@ -82,7 +89,6 @@ impl LineParser<'_> {
             y: y.try_into().unwrap(), 
             current_num: 0, 
             current_num_start: Point {x:0, y:0},
-            symbol_grid,
             gears,
             numbers
         };
@@ -115,7 +121,6 @@ impl LineParser<'_> {
     }
 
     fn process_symbol(&mut self, x: i64) {
-        self.symbol_grid.insert(Point { x, y: self.y });
         self.process_gap(x);
     }
 
@@ -152,23 +157,10 @@ impl Cell {
 struct GridNumber { start: Point, end: Point, value: u32 }
 
 impl GridNumber {
-    fn is_part(&self, symbol_grid: &HashSet<Point>) -> bool {
-        let top_left = self.start + Point { x: -1, y: -1 };
-        let top_right = self.end + Point { x: 1, y: -1 };
-        for p in top_left.to_inclusive(top_right) {
-            if symbol_grid.contains(&p) { return true }
-        }
-
-        let bottom_left = self.start + Point { x: -1, y: 1 };
-        let bottom_right = self.end + Point { x: 1, y: 1 };
-        for p in bottom_left.to_inclusive(bottom_right) {
-            if symbol_grid.contains(&p) { return true }
-        }
-
-        let left = self.start + Point { x: -1, y: 0 };
-        if symbol_grid.contains(&left) { return true }
-        let right = self.end + Point { x: 1, y: 0 };
-        symbol_grid.contains(&right)
+    fn is_part(&self, grid: &Grid<Cell>) -> bool {
+        self.start.to_inclusive(self.end)
+            .flat_map(|digit| grid.neighbours_with_diagonals(&digit))
+            .any(|p| matches!(grid[&p], Cell::Symbol | Cell::Gear))
     }
 
     fn touches(&self, p: &Point) -> bool {
@@ -0,0 +1,72 @@
+use crate::{error::{self, AocError}, solution::Solution};
+
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        crate::parse_lines(lines)
+    }
+
+    fn part1(cards: &Self::Input) -> u32 {
+        cards.iter().map(Card::score).sum() // 21558
+    }
+
+    fn part2(cards: &Self::Input) -> u32 {
+        let num_winners: Vec<_> = cards.iter().map(Card::count_winners).collect();
+        let len = num_winners.len();
+        let mut num_cards = vec![1; len];
+
+        for i in 0..len {
+            let num_copies = num_cards[i];
+            let winners: usize = num_winners[i].try_into().unwrap();
+            for num_card in num_cards.iter_mut().skip(i+1).take(winners) {
+                *num_card += num_copies;
+            }
+        }
+
+        num_cards.iter().sum() // 10425665
+    }
+}
+
+pub struct Card {
+    winners: HashSet<usize>,
+    values: Vec<usize>,
+}
+
+impl Card {
+    fn score(&self) -> u32 {
+        let count = self.count_winners();
+
+        if count == 0 { 0 } else { 2_u32.pow(count - 1) }
+    }
+
+    fn count_winners(&self) -> u32 {
+        self.values.iter()
+            .filter(|v| self.winners.contains(v))
+            .count().try_into().unwrap()
+    }
+}
+
+impl FromStr for Card {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AocError::invalid(s, "Expected 'Card n: winners | values'");
+        let (_, numbers) = s.split_once(':').ok_or_else(invalid)?;
+        let (winners, values) = numbers.split_once('|').ok_or_else(invalid)?;
+
+        Ok(Card { winners: parse_numbers_list(winners)?, values: parse_numbers_list(values)? })
+    }
+}
+
+fn parse_numbers_list<C: FromIterator<usize>>(s: &str) -> Result<C, AocError> {
+    s.split_ascii_whitespace().map(error::parse).collect()
+}
@@ -0,0 +1,171 @@
+use crate::{error::{self, AocError}, interval::IntervalSet, range_map::RangeMap, solution::Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Almanac;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut lines = lines.enumerate();
+        let (_, seeds) = lines.next().ok_or_else(|| AocError::new("Empty input"))?;
+        let (seeds, seed_ranges) = parse_seeds(&seeds).map_err(|e| e.at_line(0, &seeds))?;
+        lines.next(); // blank line
+
+        let mut mappings = Vec::new();
+        while let Some(_heading) = lines.next() { // each map
+            mappings.push(parse_mapping(&mut lines)?);
+        }
+
+        Ok(Almanac { seeds, seed_ranges, mappings })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::process(input) // 175622908
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::process(input) // 5200543
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    /// Part 2 reads the seeds as (start, length) pairs
+    seed_ranges: IntervalSet<usize>,
+    mappings: Vec<RangeMap<usize>>,
+}
+
+impl Almanac {
+    /// All the mappings composed into one, so each seed or range of seeds is only looked up once
+    fn seed_to_location(&self) -> RangeMap<usize> {
+        self.mappings.iter().fold(RangeMap::new(), |map, next| map.then(next))
+    }
+}
+
+fn parse_seeds(line: &str) -> Result<(Vec<usize>, IntervalSet<usize>), AocError> {
+    let (_, seeds) = line.split_once(':').ok_or_else(|| AocError::new("Expected 'seeds: ...'"))?;
+    let seeds: Vec<usize> = seeds.split_ascii_whitespace().map(error::parse).collect::<Result<_, _>>()?;
+
+    let pairs = seeds.chunks_exact(2);
+    if !pairs.remainder().is_empty() { return Err(AocError::new("Expected seeds in (start, length) pairs")) }
+    let seed_ranges = pairs
+        .map(|pair| pair[0].checked_add(pair[1]).map(|end| pair[0]..end)
+            .ok_or_else(|| AocError::new(format!("Seed range starting at {} overflows", pair[0]))))
+        .collect::<Result<_, _>>()?;
+    Ok((seeds, seed_ranges))
+}
+
+struct Part1 {}
+
+impl Part1 {
+    fn process(almanac: &Almanac) -> usize {
+        let seed_to_location = almanac.seed_to_location();
+        almanac.seeds.iter().map(|seed| seed_to_location.get(*seed)).min().unwrap()
+    }
+}
+
+struct Part2 {}
+
+impl Part2 {
+    fn process(almanac: &Almanac) -> usize {
+        almanac.seed_to_location().map_intervals(&almanac.seed_ranges).min().unwrap()
+    }
+}
+
+/// Lines are numbered, so that any error can be located
+fn parse_mapping<I: Iterator<Item=(usize, String)>>(lines: &mut I) -> Result<RangeMap<usize>, AocError> {
+    let mut mapping = RangeMap::new();
+    for (i, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+        let Line { dest_start, source_start, len } = line.parse().map_err(|e: AocError| e.at_line(i, &line))?;
+        let source_end = source_start.checked_add(len).ok_or_else(|| AocError::new("Source range overflows").at_line(i, &line))?;
+        mapping.try_insert(source_start..source_end, dest_start).map_err(|e| AocError::new(e.to_string()).at_line(i, &line))?;
+    }
+    Ok(mapping)
+}
+
+struct Line {
+    dest_start: usize,
+    source_start: usize,
+    len: usize,
+}
+
+impl std::str::FromStr for Line {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Line, AocError> {
+        let values = s.split(' ').map(error::parse).collect::<Result<Vec<usize>, _>>()?;
+        if values.len() != 3 { return Err(AocError::new("Expected 'destination source length'")) }
+        Ok(Line {
+            dest_start: values[0],
+            source_start: values[1],
+            len: values[2]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    fn example() -> Almanac {
+        Day5::parse(EXAMPLE.lines().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn example_answers() {
+        let almanac = example();
+        assert_eq!(Day5::part1(&almanac), 35);
+        assert_eq!(Day5::part2(&almanac), 46);
+    }
+
+    #[test]
+    fn locations_back_to_seeds() {
+        let almanac = example();
+        let seed_to_location = almanac.seed_to_location();
+        // every map is a bijection, so their composition is too
+        let location_to_seed = seed_to_location.invert().unwrap();
+        assert!((0..200).all(|seed| location_to_seed.get(seed_to_location.get(seed)) == seed));
+
+        // part 2 the other way round, searching up from location 0 for the first with a starting seed
+        let lowest = (0..).find(|location| almanac.seed_ranges.contains(location_to_seed.get(*location)));
+        assert_eq!(lowest, Some(46));
+    }
+}
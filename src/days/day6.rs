@@ -0,0 +1,89 @@
+use std::cmp::Ordering;
+
+use crate::{error::{self, AocError}, solution::Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = Races;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut lines = lines.enumerate();
+        let mut next_line = || {
+            let (i, line) = lines.next().ok_or_else(|| AocError::new("Expected time and distance lines"))?;
+            parse_line(&line).map_err(|e| e.at_line(i, &line))
+        };
+        let (times, time) = next_line()?;
+        let (distances, distance) = next_line()?;
+        if times.len() != distances.len() { return Err(AocError::new("Expected a distance for each time")) }
+        Ok(Races { times, distances, time, distance })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::process(input) // 160816
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::process(input) // 46561107
+    }
+}
+
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    /// Part 2 reads each line as a single number, ignoring the spaces
+    time: usize,
+    distance: usize,
+}
+
+/// The values on the line, and all their digits read as one number
+fn parse_line(s: &str) -> Result<(Vec<usize>, usize), AocError> {
+    let (_, values) = s.split_once(':').ok_or_else(|| AocError::new("Expected 'Heading: values'"))?;
+    let separate = values.split_ascii_whitespace().map(error::parse).collect::<Result<_, _>>()?;
+    let joined = error::parse(&values.split_ascii_whitespace().collect::<String>())?;
+    Ok((separate, joined))
+}
+
+struct Part1 {}
+
+impl Part1 {
+    fn process(races: &Races) -> usize {
+        races.times.iter().zip(races.distances.iter())
+            .map(|(time, distance)| ways_to_beat_distance(*time, *distance))
+            .product()
+    }
+}
+
+struct Part2 {}
+
+impl Part2 {
+    fn process(races: &Races) -> usize {
+        ways_to_beat_distance(races.time, races.distance)
+    }
+}
+
+fn ways_to_beat_distance(time: usize, distance: usize) -> usize {
+    let score_to_reach = distance + 1; // need to beat the record
+    let mid = time / 2;
+    let minimum = search(1, mid, |t| (t*(time-t)).cmp(&score_to_reach));
+    let maximum = time - minimum; // problem is symmetric
+    (maximum + 1) - minimum
+}
+
+/// Returns a value evaluating to Equal, or the index after the last value evaulating to Less
+/// if no values returning Equal could be found.
+fn search<F: Fn(usize) -> Ordering>(mut start: usize, mut end: usize, f: F) -> usize {
+    while end > start {
+        let mid = (start + end) / 2;
+        match f(mid) {
+            Ordering::Less => start = mid + 1,
+            Ordering::Equal => return mid,
+            Ordering::Greater => end = mid
+        }
+    }
+    start
+}
@@ -1,8 +1,31 @@
 use std::{cmp::Ordering, collections::HashMap, str::FromStr};
 
-fn main() {
-    Part1::process();
-    Part2::process();
+use crate::{error::{self, AocError}, solution::Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    // Hands are ranked differently in each part, so can't be parsed until the part is known
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let lines: Vec<String> = lines.collect();
+        // Both rankings have the same cards, so checking the hands against part 1's is enough
+        crate::parse_lines::<Bid<Part1>>(lines.iter().cloned())?;
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Part1::process(input) // 253910319
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Part2::process(input) // 254083736
+    }
 }
 
 static PART1_CARDS: [char; 13] = ['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'];
@@ -16,13 +39,10 @@ trait Part: Sized {
 
     fn get_counts(&self, values: [char; 5]) -> HashMap<char, usize>;
 
-    fn process() {
-        let mut bids: Vec<Bid<Self>> = rust_aoc::read_input(7).map(|s| s.parse()).map(Result::unwrap).collect();
+    fn process(lines: &[String]) -> usize {
+        let mut bids: Vec<Bid<Self>> = lines.iter().map(|s| s.parse()).map(Result::unwrap).collect();
         bids.sort_by(|first, second| first.hand.cmp(&second.hand));
-        let total: usize = bids.iter().rev().enumerate().map(|(i, bid)| (i+1)*bid.bid).sum();
-        // 1. 253910319
-        // 2. 254083736
-        println!("Total: {total}"); 
+        bids.iter().rev().enumerate().map(|(i, bid)| (i+1)*bid.bid).sum()
     }
 
     fn get_type(&self, values: [char; 5]) -> HandType {
@@ -89,11 +109,11 @@ struct Bid<Ranking: Part> {
 }
 
 impl<Ranking: Part> FromStr for Bid<Ranking> {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, ()> {
-        let (cards, bid) = rust_aoc::split_in_two(s, ' ');
-        let hand = cards.parse().unwrap();
-        let bid = bid.parse().unwrap();
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let (cards, bid) = s.split_once(' ').ok_or_else(|| AocError::new("Expected 'hand bid'"))?;
+        let hand = cards.parse()?;
+        let bid = error::parse(bid)?;
         let bid = Bid { hand, bid };
         Ok(bid)
     }
@@ -117,10 +137,14 @@ impl<Ranking: Part> Hand<Ranking> {
 }
 
 impl<Ranking: Part> FromStr for Hand<Ranking> {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self,()> {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self,AocError> {
         let ranking = Ranking::new();
-        let cards = s.chars().collect::<Vec<_>>().try_into().unwrap();
+        let cards: [char; 5] = s.chars().collect::<Vec<_>>().try_into()
+            .map_err(|_| AocError::invalid(s, "Expected 5 cards"))?;
+        if let Some(card) = cards.iter().find(|card| !ranking.get_card_ranks().contains(card)) {
+            return Err(AocError::invalid(*card, "Unknown card"))
+        }
         let hand_type = ranking.get_type(cards);
         let hand = Hand::<Ranking> { cards, hand_type, ranking };
         Ok(hand)
@@ -133,8 +157,8 @@ impl<Ranking: Part> Ord for Hand<Ranking> {
             Ordering::Equal => {
                 self.get_card_ranks().zip(other.get_card_ranks())
                     .map(|(i1, i2)| i1.cmp(&i2))
-                    .filter(|order| *order != Ordering::Equal)
-                    .next().unwrap()
+                    .find(|order| *order != Ordering::Equal)
+                    .unwrap()
             },
             order => order
         }
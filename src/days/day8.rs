@@ -0,0 +1,191 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crate::{cycle::Cycle, error::AocError, periodic::PeriodicSet, solution::Solution};
+
+pub struct Day8;
+
+// Steps before cycling: ~300
+// Total number of nodes: ~700
+// Worst case loop length: ~200k
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Network;
+    type Answer1 = Steps;
+    type Answer2 = Steps;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        let mut lines = lines.enumerate();
+        let (_, first) = lines.next().ok_or_else(|| AocError::new("Empty input"))?;
+        let directions = first.chars().enumerate()
+            .map(|(x, c)| Direction::try_from(c).map_err(|e| e.at_column(x).at_line(0, &first)))
+            .collect::<Result<_, _>>()?;
+
+        // blank line
+        lines.next();
+
+        let nodes: HashMap<String, Node> = lines
+            .map(|(i, line)| line.parse().map_err(|e: AocError| e.at_line(i, &line)))
+            .map(|node| node.map(|node: Node| (node.name.clone(), node)))
+            .collect::<Result<_, _>>()?;
+        if let Some(missing) = nodes.values().flat_map(|node| [&node.left, &node.right]).find(|name| !nodes.contains_key(*name)) {
+            return Err(AocError::invalid(missing.as_str(), "Unknown node"))
+        }
+
+        Ok(Network { directions, nodes })
+    }
+
+    fn part1(input: &Self::Input) -> Steps {
+        Part1::process(input) // 22357
+    }
+
+    fn part2(input: &Self::Input) -> Steps {
+        Part2::process(input) // 10371555451871
+    }
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+/// Every path might never be at an endpoint at the same time, or only after too many steps to count
+pub enum Steps {
+    After(usize),
+    Never,
+    Overflow,
+}
+
+impl Display for Steps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Steps::After(steps) => write!(f, "{steps}"),
+            Steps::Never => write!(f, "Never all at an endpoint together"),
+            Steps::Overflow => write!(f, "Combined period overflows"),
+        }
+    }
+}
+
+trait Part: Sized {
+    fn initial_names(nodes: &HashMap<String, Node>) -> Vec<&str>;
+
+    fn finished_node(name: &str) -> bool;
+
+    /// Number of start nodes: 6
+    /// Worst case complexity: ~1.2M
+    fn process(Network { directions, nodes }: &Network) -> Steps {
+        let start_names = Self::initial_names(nodes);
+
+        let endpoints: Vec<_> = start_names.iter().map(|start| endpoints::<Self>(start, directions, nodes)).collect();
+
+        let Some((first, rest)) = endpoints.split_first() else { return Steps::Never };
+        match rest.iter().try_fold(first.clone(), |all, next| all.intersect(next)) {
+            Some(overall_endpoints) => overall_endpoints.first().map_or(Steps::Never, Steps::After),
+            None => Steps::Overflow,
+        }
+    }
+}
+
+struct Part1;
+
+impl Part for Part1 {
+    fn initial_names(_: &HashMap<String, Node>) -> Vec<&str> {
+        vec!["AAA"]
+    }
+
+    fn finished_node(name: &str) -> bool {
+        name == "ZZZ"
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    fn initial_names(nodes: &HashMap<String, Node>) -> Vec<&str> {
+        nodes.keys().filter(|key| key.ends_with('A')).map(std::ops::Deref::deref).collect()
+    }
+
+    fn finished_node(name: &str) -> bool {
+        name.ends_with('Z')
+    }
+}
+
+/// Steps at which the path from start is at an endpoint. The path is in a cycle once it reaches the same node
+/// at the same point in the directions.
+fn endpoints<Rules: Part>(start: &str, dirs: &[Direction], nodes: &HashMap<String, Node>) -> PeriodicSet {
+    let path = Cycle::find((0, start), |&(dir_idx, name)| ((dir_idx + 1) % dirs.len(), step(name, dirs[dir_idx], nodes)));
+    PeriodicSet::from_cycle(&path, |(_, name)| Rules::finished_node(name))
+}
+
+#[derive(Eq, PartialEq, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(AocError::invalid(c, "Unrecognised direction"))
+        }
+    }
+}
+
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl FromStr for Node {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // AAA = (BBB, CCC)
+        let (name, targets) = s.split_once(" = ").ok_or_else(|| AocError::new("Expected 'AAA = (BBB, CCC)'"))?;
+        let (left, right) = targets.strip_prefix('(').and_then(|targets| targets.strip_suffix(')'))
+            .and_then(|targets| targets.split_once(", "))
+            .ok_or_else(|| AocError::invalid(targets, "Expected '(BBB, CCC)'"))?;
+        Ok(Node { name: String::from(name), left: String::from(left), right: String::from(right) })
+    }
+}
+
+fn step<'a>(name: &str, dir: Direction, nodes: &'a HashMap<String, Node>) -> &'a str {
+    let node = nodes.get(name).unwrap();
+    if dir == Direction::Left { &node.left } else { &node.right }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn ghosts_meet() {
+        let network = Day8::parse(EXAMPLE.lines().map(String::from)).unwrap();
+        assert_eq!(Day8::part2(&network).to_string(), "6");
+    }
+
+    #[test]
+    fn ghosts_never_meet() {
+        // 11A reaches 11Z after an even number of steps, 33A reaches 33Z after an odd number
+        let input = format!("{EXAMPLE}\n33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33Z, 33Z)");
+        let network = Day8::parse(input.lines().map(String::from)).unwrap();
+        assert!(matches!(Day8::part2(&network), Steps::Never));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{error::{self, AocError}, solution::Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError> {
+        lines.enumerate()
+            .map(|(i, line)| parse_line(&line).map_err(|e| e.at_line(i, &line)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        Part1::process(input) // 1819125966
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        Part2::process(input) // 1140
+    }
+}
+
+trait Part {
+    fn predict_impl(values: Vec<i64>, next_delta: i64) -> i64;
+
+    fn process(input: &[Vec<i64>]) -> i64 {
+        input.iter().cloned().map(Self::predict).sum()
+    }
+
+    fn predict(values: Vec<i64>) -> i64 {
+        if values.iter().all(|v| *v == 0) {
+            0
+        } else {
+            let next_delta = Self::predict(Self::get_deltas(&values));
+            Self::predict_impl(values, next_delta)
+        }
+    }
+
+    fn get_deltas(values: &[i64]) -> Vec<i64> {
+        values.windows(2).map(|window| window[1] - window[0]).collect()
+    }
+}
+
+struct Part1;
+
+impl Part for Part1 {
+    fn predict_impl(values: Vec<i64>, next_delta: i64) -> i64 {
+        values.last().unwrap() + next_delta
+    }
+}
+
+struct Part2;
+
+impl Part for Part2 {
+    fn predict_impl(values: Vec<i64>, next_delta: i64) -> i64 {
+        values[0] - next_delta
+    }
+}
+
+fn parse_line(s: &str) -> Result<Vec<i64>, AocError> {
+    s.split_ascii_whitespace().map(error::parse).collect()
+}
\ No newline at end of file
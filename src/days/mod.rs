@@ -0,0 +1,61 @@
+use crate::solution::{solve, Solver};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const ALL_DAYS: std::ops::RangeInclusive<u32> = 1..=25;
+
+pub fn get_solver(day: u32) -> Option<Solver> {
+    let solver: Solver = match day {
+        1 => solve::<day1::Day1>,
+        2 => solve::<day2::Day2>,
+        3 => solve::<day3::Day3>,
+        4 => solve::<day4::Day4>,
+        5 => solve::<day5::Day5>,
+        6 => solve::<day6::Day6>,
+        7 => solve::<day7::Day7>,
+        8 => solve::<day8::Day8>,
+        9 => solve::<day9::Day9>,
+        10 => solve::<day10::Day10>,
+        11 => solve::<day11::Day11>,
+        12 => solve::<day12::Day12>,
+        13 => solve::<day13::Day13>,
+        14 => solve::<day14::Day14>,
+        15 => solve::<day15::Day15>,
+        16 => solve::<day16::Day16>,
+        17 => solve::<day17::Day17>,
+        18 => solve::<day18::Day18>,
+        19 => solve::<day19::Day19>,
+        20 => solve::<day20::Day20>,
+        21 => solve::<day21::Day21>,
+        22 => solve::<day22::Day22>,
+        23 => solve::<day23::Day23>,
+        24 => solve::<day24::Day24>,
+        25 => solve::<day25::Day25>,
+        _ => return None,
+    };
+    Some(solver)
+}
@@ -1,5 +1,5 @@
-use std::{io::{Error, ErrorKind}, ops::{Add, AddAssign}};
-use crate::point::Point;
+use std::ops::{Add, AddAssign};
+use crate::{error::AocError, point::Point};
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
 pub enum Direction {
@@ -49,13 +49,13 @@ impl AddAssign<Direction> for Point {
 }
 
 impl TryFrom<Point> for Direction {
-    type Error = Error;
+    type Error = AocError;
 
     fn try_from(value: Point) -> Result<Self, Self::Error> {
-        if let Some(d) = Direction::all().iter().filter(|d| Point::from(**d) == value).next() {
+        if let Some(d) = Direction::all().iter().find(|d| Point::from(**d) == value) {
             Ok(*d)
         } else {
-            Err(Error::new(ErrorKind::InvalidInput, format!("{value} does not correspond to a direction")))
+            Err(AocError::new(format!("{value} does not correspond to a direction")))
         }
     }
 }
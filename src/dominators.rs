@@ -0,0 +1,203 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+/// A node d dominates n if every path from the root to n passes through d, so removing d
+/// cuts n off from the root. Every node dominates itself.
+pub trait Dominators {
+    type Node: Hash + Eq;
+
+    fn get_edges(&self, node: &Self::Node) -> Vec<&Self::Node>;
+
+    /// Cooper, Harvey and Kennedy's iterative algorithm, "A Simple, Fast Dominance Algorithm".
+    /// Only nodes reachable from the root are included.
+    fn dominator_tree<'a>(&'a self, root: &'a Self::Node) -> DominatorTree<&'a Self::Node> {
+        let nodes = reverse_postorder(self, root);
+        let index: HashMap<&Self::Node, usize> = nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect();
+
+        let mut predecessors = vec![Vec::new(); nodes.len()];
+        for (i, node) in nodes.iter().enumerate() {
+            for next in self.get_edges(node) {
+                predecessors[index[next]].push(i);
+            }
+        }
+
+        // Indices are in reverse postorder, so lower indices are closer to the root, and the root is 0
+        let mut idoms: Vec<Option<usize>> = vec![None; nodes.len()];
+        idoms[0] = Some(0);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 1..nodes.len() {
+                let new_idom = predecessors[i].iter()
+                    .filter(|p| idoms[**p].is_some())
+                    .copied()
+                    .reduce(|a, b| intersect(&idoms, a, b));
+                if new_idom.is_some() && idoms[i] != new_idom {
+                    idoms[i] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        let idoms: Vec<usize> = idoms.into_iter().map(Option::unwrap).collect();
+
+        let mut children = vec![Vec::new(); nodes.len()];
+        for (i, idom) in idoms.iter().enumerate().skip(1) {
+            children[*idom].push(i);
+        }
+        // a node's immediate dominator always comes before it in reverse postorder, so totals can be built backwards
+        let mut subtree_sizes = vec![1; nodes.len()];
+        for i in (1..nodes.len()).rev() {
+            subtree_sizes[idoms[i]] += subtree_sizes[i];
+        }
+
+        DominatorTree { nodes, index, idoms, children, subtree_sizes }
+    }
+}
+
+/// Walks both nodes up the (partially built) tree until they meet at their nearest common dominator
+fn intersect(idoms: &[Option<usize>], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while a > b { a = idoms[a].unwrap() }
+        while b > a { b = idoms[b].unwrap() }
+    }
+    a
+}
+
+fn reverse_postorder<'a, G: Dominators + ?Sized>(graph: &'a G, root: &'a G::Node) -> Vec<&'a G::Node> {
+    let mut postorder = Vec::new();
+    let mut seen = HashSet::new();
+    seen.insert(root);
+    // (node, its edges, index of the next edge to follow), in place of recursion
+    let mut stack = vec![(root, graph.get_edges(root), 0)];
+    while let Some((node, edges, next_edge)) = stack.last_mut() {
+        if let Some(next) = edges.get(*next_edge).copied() {
+            *next_edge += 1;
+            if seen.insert(next) {
+                stack.push((next, graph.get_edges(next), 0));
+            }
+        } else {
+            postorder.push(*node);
+            stack.pop();
+        }
+    }
+    postorder.reverse();
+    postorder
+}
+
+pub struct DominatorTree<T> {
+    /// Reverse postorder of a DFS from the root, so the root is first
+    nodes: Vec<T>,
+    index: HashMap<T, usize>,
+    idoms: Vec<usize>,
+    children: Vec<Vec<usize>>,
+    subtree_sizes: Vec<usize>,
+}
+
+impl<T: Hash + Eq + Copy> DominatorTree<T> {
+    pub fn root(&self) -> T {
+        self.nodes[0]
+    }
+
+    /// Nodes reachable from the root, the root first
+    pub fn nodes(&self) -> impl Iterator<Item=T> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    pub fn contains(&self, node: T) -> bool {
+        self.index.contains_key(&node)
+    }
+
+    /// The closest strict dominator, None for the root and for nodes unreachable from it
+    pub fn immediate_dominator(&self, node: T) -> Option<T> {
+        match self.index.get(&node) {
+            Some(0) | None => None,
+            Some(i) => Some(self.nodes[self.idoms[*i]]),
+        }
+    }
+
+    /// Nodes that node is the immediate dominator of
+    pub fn children(&self, node: T) -> Vec<T> {
+        self.index.get(&node)
+            .map(|i| self.children[*i].iter().map(|child| self.nodes[*child]).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn dominates(&self, dominator: T, node: T) -> bool {
+        let (Some(&dominator), Some(&(mut i))) = (self.index.get(&dominator), self.index.get(&node)) else { return false };
+        // dominators always come earlier in reverse postorder
+        while i > dominator { i = self.idoms[i] }
+        i == dominator
+    }
+
+    /// Number of nodes dominated by node, including itself. 0 if unreachable from the root.
+    pub fn subtree_size(&self, node: T) -> usize {
+        self.index.get(&node).map_or(0, |i| self.subtree_sizes[*i])
+    }
+
+    /// Nodes that could no longer be reached from the root if node were removed, not including node itself
+    pub fn cut_off_by_removing(&self, node: T) -> Vec<T> {
+        let Some(&i) = self.index.get(&node) else { return Vec::new() };
+        let mut cut_off = Vec::new();
+        let mut to_visit = self.children[i].clone();
+        while let Some(j) = to_visit.pop() {
+            cut_off.push(self.nodes[j]);
+            to_visit.extend(&self.children[j]);
+        }
+        cut_off
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Adjacency(Vec<Vec<usize>>);
+
+    impl Dominators for Adjacency {
+        type Node = usize;
+
+        fn get_edges(&self, node: &usize) -> Vec<&usize> {
+            self.0[*node].iter().collect()
+        }
+    }
+
+    /// 0 splits into 1 and 2, which join again at 3, followed by 4. 5 only leads into the graph.
+    fn diamond() -> Adjacency {
+        Adjacency(vec![vec![1, 2], vec![3], vec![3], vec![4], vec![], vec![3]])
+    }
+
+    #[test]
+    fn diamond_joins_at_root() {
+        let graph = diamond();
+        let tree = graph.dominator_tree(&0);
+        assert_eq!(tree.root(), &0);
+        let idoms: Vec<Option<&usize>> = [1, 2, 3, 4].iter().map(|n| tree.immediate_dominator(n)).collect();
+        assert_eq!(idoms, [Some(&0), Some(&0), Some(&0), Some(&3)]);
+        assert_eq!(tree.immediate_dominator(&0), None);
+        assert!(tree.dominates(&0, &4) && tree.dominates(&3, &4) && tree.dominates(&4, &4));
+        assert!(!tree.dominates(&1, &3));
+        assert_eq!(tree.subtree_size(&0), 5);
+        assert_eq!(tree.subtree_size(&3), 2);
+        assert_eq!(tree.cut_off_by_removing(&3), [&4]);
+        assert!(tree.cut_off_by_removing(&1).is_empty());
+    }
+
+    #[test]
+    fn unreachable_nodes_are_left_out() {
+        let graph = diamond();
+        let tree = graph.dominator_tree(&0);
+        assert!(!tree.contains(&5));
+        assert_eq!(tree.nodes().count(), 5);
+        assert_eq!(tree.immediate_dominator(&5), None);
+        assert_eq!(tree.subtree_size(&5), 0);
+    }
+
+    #[test]
+    fn loop_back_into_branch() {
+        // 4 leads back to 1, which can still be reached around it through 0 alone
+        let graph = Adjacency(vec![vec![1, 2], vec![3], vec![3], vec![4], vec![1]]);
+        let tree = graph.dominator_tree(&0);
+        assert_eq!(tree.immediate_dominator(&1), Some(&0));
+        assert_eq!(tree.immediate_dominator(&4), Some(&3));
+        assert_eq!(tree.children(&0).len(), 3);
+    }
+}
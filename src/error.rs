@@ -0,0 +1,113 @@
+use std::{convert::Infallible, error::Error, fmt::Display, io, str::FromStr};
+
+use crate::input::InputError;
+
+/// An invalid puzzle input, located as precisely as possible.
+/// Parsers usually only know the text they couldn't make sense of, so the line is filled in by
+/// parse_lines or Grid::try_parse_lines, and the day by solution::solve.
+#[derive(Debug)]
+pub struct AocError {
+    pub day: Option<u32>,
+    /// 1-based
+    pub line: Option<usize>,
+    /// 1-based, in characters
+    pub column: Option<usize>,
+    /// The offending text, either a whole line or the part of it that couldn't be parsed
+    pub text: Option<String>,
+    pub message: String,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError { day: None, line: None, column: None, text: None, message: message.into(), source: None }
+    }
+
+    pub fn invalid(text: impl Into<String>, message: impl Into<String>) -> Self {
+        AocError { text: Some(text.into()), ..Self::new(message) }
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Locates the error on the line with the given 0-based index. If the offending text is known and
+    /// appears in the line, its first occurrence is taken as the column, otherwise the line is the text.
+    pub fn at_line(mut self, index: usize, line: &str) -> Self {
+        if self.line.is_some() { return self }
+        self.line = Some(index + 1);
+        match &self.text {
+            Some(text) => if self.column.is_none() {
+                self.column = line.find(text.as_str()).map(|byte| line[..byte].chars().count() + 1);
+            },
+            None => self.text = Some(String::from(line)),
+        }
+        self
+    }
+
+    /// 0-based index of the character within the line
+    pub fn at_column(mut self, index: usize) -> Self {
+        self.column.get_or_insert(index + 1);
+        self
+    }
+}
+
+/// Parses text with FromStr, keeping the text in the error if it fails
+pub fn parse<T: FromStr>(text: &str) -> Result<T, AocError> where T::Err: Display {
+    text.parse().map_err(|e| AocError::invalid(text, format!("Invalid value ({e})")))
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location: Vec<_> = [("Day", self.day.map(|day| day as usize)), ("line", self.line), ("column", self.column)]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{name} {value}")))
+            .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, " {text:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|source| source.as_ref() as &(dyn Error + 'static))
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(error: InputError) -> Self {
+        AocError { message: error.to_string(), source: Some(Box::new(error)), ..Self::new("") }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> Self {
+        AocError { message: error.to_string(), source: Some(Box::new(error)), ..Self::new("") }
+    }
+}
+
+impl From<String> for AocError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// For conversions that can't fail, e.g. parsing a Grid<char>
+impl From<Infallible> for AocError {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl From<()> for AocError {
+    fn from(_: ()) -> Self {
+        Self::new("Invalid input")
+    }
+}
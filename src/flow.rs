@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+
+use crate::graph::NodeId;
+
+/// Directed network of capacities between nodes 0..len, for finding maximum flows and minimum s-t cuts
+#[derive(Clone, Debug, Default)]
+pub struct FlowNetwork {
+    /// Stored in pairs, so arc i ^ 1 is the reverse of arc i and carries its residual capacity
+    arcs: Vec<Arc>,
+    outgoing: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Debug)]
+struct Arc {
+    to: NodeId,
+    capacity: usize,
+}
+
+/// A maximum flow, and the minimum cut that proves it can't be increased
+#[derive(Clone, Debug)]
+pub struct MaxFlow {
+    pub value: usize,
+    /// Nodes still reachable from the source in the residual network, always including the source
+    pub source_side: Vec<NodeId>,
+    /// Saturated arcs from the source side to the rest, with their capacities, which add up to the value
+    pub cut: Vec<(NodeId, NodeId, usize)>,
+}
+
+impl FlowNetwork {
+    pub fn new(len: usize) -> Self {
+        FlowNetwork { arcs: Vec::new(), outgoing: vec![Vec::new(); len] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.outgoing.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outgoing.is_empty()
+    }
+
+    pub fn add_node(&mut self) -> NodeId {
+        self.outgoing.push(Vec::new());
+        self.outgoing.len() - 1
+    }
+
+    /// One-way arc
+    pub fn add_arc(&mut self, from: NodeId, to: NodeId, capacity: usize) {
+        self.add_pair(from, to, capacity, 0);
+    }
+
+    /// Undirected edge, which flow can cross in either direction up to its capacity
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId, capacity: usize) {
+        self.add_pair(a, b, capacity, capacity);
+    }
+
+    fn add_pair(&mut self, from: NodeId, to: NodeId, capacity: usize, reverse_capacity: usize) {
+        self.outgoing[from].push(self.arcs.len());
+        self.arcs.push(Arc { to, capacity });
+        self.outgoing[to].push(self.arcs.len());
+        self.arcs.push(Arc { to: from, capacity: reverse_capacity });
+    }
+
+    fn tail(&self, arc: usize) -> NodeId {
+        self.arcs[arc ^ 1].to
+    }
+
+    /// Dinic's algorithm: repeatedly layers the residual network by distance from the source, then saturates
+    /// it with shortest augmenting paths. O(V²E) in general, and O(E√V) with unit capacities.
+    pub fn max_flow(&self, source: NodeId, sink: NodeId) -> MaxFlow {
+        let mut residual: Vec<usize> = self.arcs.iter().map(|arc| arc.capacity).collect();
+        let mut value = 0;
+        if source != sink {
+            loop {
+                let mut levels = self.levels(&residual, source);
+                if levels[sink].is_none() { break }
+                let mut next_arc = vec![0; self.len()];
+                while let Some(path) = self.augmenting_path(&residual, &mut levels, &mut next_arc, source, sink) {
+                    let bottleneck = path.iter().map(|arc| residual[*arc]).min().unwrap();
+                    for arc in path {
+                        residual[arc] -= bottleneck;
+                        residual[arc ^ 1] += bottleneck;
+                    }
+                    value += bottleneck;
+                }
+            }
+        }
+
+        // With no augmenting paths left, the nodes still reachable from the source form a minimum cut
+        let levels = self.levels(&residual, source);
+        let source_side = (0..self.len()).filter(|node| levels[*node].is_some()).collect();
+        let cut = (0..self.arcs.len())
+            .filter(|arc| self.arcs[*arc].capacity > 0)
+            .filter(|arc| levels[self.tail(*arc)].is_some() && levels[self.arcs[*arc].to].is_none())
+            .map(|arc| (self.tail(arc), self.arcs[arc].to, self.arcs[arc].capacity))
+            .collect();
+        MaxFlow { value, source_side, cut }
+    }
+
+    /// Distance from the source of each node, using only arcs with capacity left. None if unreachable.
+    fn levels(&self, residual: &[usize], source: NodeId) -> Vec<Option<usize>> {
+        let mut levels = vec![None; self.len()];
+        levels[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &arc in &self.outgoing[node] {
+                let to = self.arcs[arc].to;
+                if residual[arc] > 0 && levels[to].is_none() {
+                    levels[to] = levels[node].map(|level| level + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+        levels
+    }
+
+    /// Arcs of a path from source to sink that only goes one level further each step.
+    /// next_arc remembers how far through each node's arcs the search has got, and dead ends are
+    /// removed from the levels, so that each arc is only tried once per phase.
+    fn augmenting_path(&self, residual: &[usize], levels: &mut [Option<usize>], next_arc: &mut [usize], source: NodeId, sink: NodeId) -> Option<Vec<usize>> {
+        // the source itself is a dead end once every path out of it is exhausted
+        levels[source]?;
+        let mut path = Vec::new();
+        let mut node = source;
+        while node != sink {
+            let arcs = &self.outgoing[node];
+            let next_level = levels[node].map(|level| level + 1);
+            while next_arc[node] < arcs.len() {
+                let arc = arcs[next_arc[node]];
+                if residual[arc] > 0 && levels[self.arcs[arc].to] == next_level { break }
+                next_arc[node] += 1;
+            }
+            match arcs.get(next_arc[node]) {
+                Some(&arc) => {
+                    path.push(arc);
+                    node = self.arcs[arc].to;
+                },
+                None => {
+                    levels[node] = None;
+                    // back up and try the previous node's next arc
+                    let arc = path.pop()?;
+                    node = self.tail(arc);
+                    next_arc[node] += 1;
+                },
+            }
+        }
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example network from Introduction to Algorithms, with source 0 and sink 5
+    fn clrs() -> FlowNetwork {
+        let mut network = FlowNetwork::new(6);
+        for (from, to, capacity) in [(0, 1, 16), (0, 2, 13), (1, 3, 12), (2, 1, 4), (2, 4, 14), (3, 2, 9), (3, 5, 20), (4, 3, 7), (4, 5, 4)] {
+            network.add_arc(from, to, capacity);
+        }
+        network
+    }
+
+    #[test]
+    fn max_flow_matches_min_cut() {
+        let flow = clrs().max_flow(0, 5);
+        assert_eq!(flow.value, 23);
+        assert_eq!(flow.source_side, [0, 1, 2, 4]);
+        let mut cut = flow.cut.clone();
+        cut.sort();
+        assert_eq!(cut, [(1, 3, 12), (4, 3, 7), (4, 5, 4)]);
+        assert_eq!(cut.iter().map(|(_, _, capacity)| capacity).sum::<usize>(), flow.value);
+    }
+
+    #[test]
+    fn arcs_only_carry_flow_one_way() {
+        assert_eq!(clrs().max_flow(5, 0).value, 0);
+        let mut network = FlowNetwork::new(2);
+        network.add_edge(0, 1, 3);
+        assert_eq!(network.max_flow(1, 0).value, 3);
+    }
+
+    #[test]
+    fn flow_is_rerouted() {
+        // the shortest path 0 -> 1 -> 2 -> 3 blocks both others until flow is sent back along 1 -> 2
+        let mut network = FlowNetwork::new(4);
+        for (from, to) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)] {
+            network.add_arc(from, to, 1);
+        }
+        assert_eq!(network.max_flow(0, 3).value, 2);
+    }
+
+    #[test]
+    fn no_flow_to_itself() {
+        let flow = clrs().max_flow(0, 0);
+        assert_eq!(flow.value, 0);
+        assert!(flow.cut.is_empty());
+    }
+}
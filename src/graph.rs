@@ -0,0 +1,416 @@
+use std::{collections::{BTreeMap, HashMap, HashSet}, f64::consts::SQRT_2, hash::Hash};
+
+use bimap::BiMap;
+use priority_queue::PriorityQueue;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::flow::FlowNetwork;
+
+pub type NodeId = usize;
+
+/// Weighted undirected graph, with nodes labelled by T
+#[derive(Clone)]
+pub struct Graph<T: Hash + Eq> {
+    node_ids: BiMap<T, NodeId>,
+    edges: Vec<HashMap<NodeId, usize>>,
+}
+
+/// A partition of the nodes into two non-empty sides
+#[derive(Clone, Debug)]
+pub struct Cut<T> {
+    /// Total weight of the crossing edges
+    pub weight: usize,
+    /// Each crossing edge once, from the first partition to the second, with its weight
+    pub edges: Vec<(T, T, usize)>,
+    pub partitions: [Vec<T>; 2],
+}
+
+impl<T: Hash + Eq + Clone> Default for Graph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Graph<T> {
+    pub fn new() -> Self {
+        Graph { node_ids: BiMap::new(), edges: Vec::new() }
+    }
+
+    /// Returns the existing ID if the node is already in the graph
+    pub fn add_node(&mut self, node: T) -> NodeId {
+        match self.node_ids.get_by_left(&node) {
+            Some(id) => *id,
+            None => {
+                let id = self.edges.len();
+                self.node_ids.insert(node, id);
+                self.edges.push(HashMap::new());
+                id
+            }
+        }
+    }
+
+    /// Adds both nodes if necessary. Adding an edge that already exists adds to its weight.
+    pub fn add_edge(&mut self, a: T, b: T, weight: usize) {
+        let (a, b) = (self.add_node(a), self.add_node(b));
+        *self.edges[a].entry(b).or_default() += weight;
+        *self.edges[b].entry(a).or_default() += weight;
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn id(&self, node: &T) -> Option<NodeId> {
+        self.node_ids.get_by_left(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &T {
+        self.node_ids.get_by_right(&id).unwrap()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item=&T> {
+        (0..self.len()).map(|id| self.node(id))
+    }
+
+    pub fn neighbours(&self, node: &T) -> impl Iterator<Item=(&T, usize)> {
+        self.id(node).into_iter()
+            .flat_map(|id| self.edges[id].iter())
+            .map(|(neighbour, weight)| (self.node(*neighbour), *weight))
+    }
+
+    /// Each edge once
+    pub fn edges(&self) -> impl Iterator<Item=(&T, &T, usize)> {
+        self.edges.iter().enumerate()
+            .flat_map(|(a, edges)| edges.iter().filter(move |(b, _)| a < **b).map(move |(b, weight)| (a, *b, *weight)))
+            .map(|(a, b, weight)| (self.node(a), self.node(b), weight))
+    }
+
+    /// Stoer-Wagner, None if there are fewer than 2 nodes
+    pub fn min_cut_stoer_wagner(&self) -> Option<Cut<T>> {
+        self.stoer_wagner(0)
+    }
+
+    /// As min_cut_stoer_wagner, but settling for the first cut found with weight at most max_weight,
+    /// which is much faster when the minimum is known in advance. None if there is no such cut.
+    pub fn find_cut_stoer_wagner(&self, max_weight: usize) -> Option<Cut<T>> {
+        self.stoer_wagner(max_weight).filter(|cut| cut.weight <= max_weight)
+    }
+
+    /// A single run of Karger-Stein, which finds a minimum cut with probability Ω(1/log n).
+    /// Stops exploring alternatives once a cut of at most good_enough is found.
+    pub fn karger_stein<R: Rng>(&self, rng: &mut R, good_enough: usize) -> Option<Cut<T>> {
+        if self.len() < 2 { return None }
+        let (graph, side, weight) = try_find_min_cut_karger_stein(Contracted::new(self), rng, good_enough);
+        Some(self.cut(&graph.members(side), weight))
+    }
+
+    /// Lightest cut from repeated runs of Karger-Stein, reproducible for a given seed
+    pub fn min_cut_karger_stein(&self, seed: u64, runs: usize) -> Option<Cut<T>> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..runs).filter_map(|_| self.karger_stein(&mut rng, 0)).min_by_key(|cut| cut.weight)
+    }
+
+    /// Lightest cut separating s from t, with s in the first partition, from the maximum flow between them.
+    /// None if either node isn't in the graph, or they're the same node.
+    pub fn min_st_cut(&self, s: &T, t: &T) -> Option<Cut<T>> {
+        let (s, t) = (self.id(s)?, self.id(t)?);
+        if s == t { return None }
+        let flow = self.flow_network().max_flow(s, t);
+        Some(self.cut(&flow.source_side, flow.value))
+    }
+
+    /// Each edge becomes an undirected edge with its weight as capacity, keeping the same node IDs
+    pub fn flow_network(&self) -> FlowNetwork {
+        let mut network = FlowNetwork::new(self.len());
+        for (a, edges) in self.edges.iter().enumerate() {
+            for (b, weight) in edges.iter().filter(|(b, _)| a < **b) {
+                network.add_edge(a, *b, *weight);
+            }
+        }
+        network
+    }
+
+    fn stoer_wagner(&self, good_enough: usize) -> Option<Cut<T>> {
+        let mut graph = Contracted::new(self);
+        let mut best: Option<(Vec<NodeId>, usize)> = None;
+        while graph.edges.len() > 1 {
+            let (s, t, weight) = minimum_cut_phase(&graph);
+            if best.as_ref().is_none_or(|(_, best_weight)| weight < *best_weight) {
+                // expand merged node t into the actual partition
+                best = Some((graph.members(t), weight));
+            }
+            if weight <= good_enough { break }
+            graph.merge_nodes(s, t);
+        }
+        best.map(|(side, weight)| self.cut(&side, weight))
+    }
+
+    fn cut(&self, side: &[NodeId], weight: usize) -> Cut<T> {
+        let in_first: HashSet<NodeId> = side.iter().copied().collect();
+        let edges = side.iter()
+            .flat_map(|a| self.edges[*a].iter().map(move |(b, weight)| (*a, *b, *weight)))
+            .filter(|(_, b, _)| !in_first.contains(b))
+            .map(|(a, b, weight)| (self.node(a).clone(), self.node(b).clone(), weight))
+            .collect();
+        let (first, second) = (0..self.len()).partition(|id| in_first.contains(id));
+        let to_nodes = |ids: Vec<NodeId>| ids.into_iter().map(|id| self.node(id).clone()).collect();
+        Cut { weight, edges, partitions: [to_nodes(first), to_nodes(second)] }
+    }
+}
+
+/// Copy of a graph's edges that nodes can be merged in. Merged nodes get new IDs, after those of the
+/// original nodes, and remember which two nodes they were merged from.
+/// Ordered maps, so that picking random edges is reproducible for a seeded RNG.
+#[derive(Clone)]
+struct Contracted {
+    edges: BTreeMap<NodeId, BTreeMap<NodeId, usize>>,
+    merged_from: Vec<Option<(NodeId, NodeId)>>,
+}
+
+impl Contracted {
+    fn new<T: Hash + Eq>(graph: &Graph<T>) -> Self {
+        Contracted {
+            edges: graph.edges.iter().map(|edges| edges.iter().map(|(b, weight)| (*b, *weight)).collect()).enumerate().collect(),
+            merged_from: vec![None; graph.edges.len()],
+        }
+    }
+
+    fn merge_nodes(&mut self, s: NodeId, t: NodeId) -> NodeId {
+        let s_neighbours = self.edges.remove(&s).unwrap();
+        let t_neighbours = self.edges.remove(&t).unwrap();
+
+        let new_node = self.merged_from.len();
+        self.merged_from.push(Some((s, t)));
+        self.edges.insert(new_node, BTreeMap::new());
+
+        for (old, neighbours) in [(s, s_neighbours), (t, t_neighbours)] {
+            for (neighbour, weight) in neighbours {
+                if neighbour == s || neighbour == t { continue; }
+                self.edges.get_mut(&neighbour).unwrap().remove(&old);
+                *self.edges.get_mut(&new_node).unwrap().entry(neighbour).or_default() += weight;
+                *self.edges.get_mut(&neighbour).unwrap().entry(new_node).or_default() += weight;
+            }
+        }
+        new_node
+    }
+
+    /// IDs of the original nodes that were merged into this one
+    fn members(&self, id: NodeId) -> Vec<NodeId> {
+        let mut members = Vec::new();
+        let mut to_expand = vec![id];
+        while let Some(id) = to_expand.pop() {
+            match self.merged_from[id] {
+                None => members.push(id),
+                Some((s, t)) => to_expand.extend([s, t]),
+            }
+        }
+        members
+    }
+}
+
+/*
+See https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+or https://citeseerx.ist.psu.edu/document?repid=rep1&type=pdf&doi=b10145f7fc3d07e43607abc2a148e58d24ced543
+
+High-level idea:
+- Given two vertices s and t, the minimum cut is either a minimum s-t cut (separating s and t),
+  or the same as a minimum cut of the graph after merging those two nodes.
+- Hence to find a minimum cut, we just need to be able to find an arbitrary s and t node and
+  their minimum s-t cut, and take the lesser of that and repeating on the graph with s and t merged.
+- We can find a minimum s-t cut by building up a set of nodes in a particular order, such that
+  s and t are the last two nodes we add, and separating off just the last node t forms a minimum s-t cut
+  (with weight being the weight of all edges out of t).
+- To do that, start from any node 'a' and build a set A by repeatedly adding the most 'tightly connected'
+  node i.e. the node with greatest total weight of its edges to nodes in A:
+  x s.t. w(A, x) >= w(A, y) for all y not in A, where w(A, x) is weight of edges from A to x.
+
+Proof:
+  Say we picked nodes in the order A = {a, ..., s, t} and consider an arbitrary s-t cut C.
+  We say v != a is 'active' if v and the previous node are on either sides of C.
+  (like induction on times we cross sides of C, but not quite, given v and the previous node may not be adjacent)
+  Let w(C) = weight of edges of cut C, A_v is the nodes of A up to but not including v, C_v is the cut of subset
+  A_v U {v} caused by the edges of C (which exists since v and the previous node are on opposite sides of C).
+
+  Lemma: For each active node v: w(A_v, v) <= w(C_v)
+  Base case, first active node v:
+    First active node, so all of A_v on same side of C, hence w(C_v) = w(A_v, v), just the edges between A_v to v.
+  Assume up to active node v, then consider next active node u:
+    w(A_u, u) = w(A_v, u) + w(A_u / A_v, u)
+    - we previously chose v because it was the most tightly connected to A_v, so w(A_v, v) >= w(A_v, u)
+    w(A_u, u) <= w(A_v, v) + w(A_u / A_v, u)
+    - induction
+    w(A_u, u) <= w(C_v) + w(A_u / A_v, u)
+    - The edges counted in w(A_u / A_v, u) are all edges to u, and u is not in A_v U {v}, so none are already counted in w(C_v).
+      (A_u / A_v) = {v, ...} are all nodes on the same side as v, and opposite side to u, since u was the next active node,
+      hence every edge counted in w(A_u / A_v, u) is an edge across sides of C and part of w(C_u)
+    w(A_u, u) <= w(C_v) + w(A_u / A_v, u) <= w(C_u)
+
+  As C is an s-t cut, the last node t is always active, and C_t = V (all nodes), so w(V / {t}, t) <= w(C).
+  Hence the cut that just removes the last node t is the minimum s-t cut for last two nodes s, t.
+ */
+fn minimum_cut_phase(graph: &Contracted) -> (NodeId, NodeId, usize) {
+    let mut queue: PriorityQueue<NodeId, usize> = PriorityQueue::new();
+    for node in graph.edges.keys() {
+        queue.push(*node, 0);
+    }
+    let mut found = vec![];
+    let mut last_weight = 0;
+
+    while !queue.is_empty() {
+        let (node, cut_weight) = remove_node_and_update_weights(&mut queue, graph);
+        found.push(node);
+        last_weight = cut_weight;
+    }
+
+    let mut it = found.into_iter().rev();
+    let last = it.next().unwrap();
+    let second_last = it.next().unwrap();
+    (second_last, last, last_weight)
+}
+
+fn remove_node_and_update_weights(queue: &mut PriorityQueue<NodeId, usize>, graph: &Contracted) -> (NodeId, usize) {
+    let (node, cut_weight) = queue.pop().unwrap();
+    for (neighbour, edge_weight) in &graph.edges[&node] {
+        queue.change_priority_by(neighbour, |p| *p += edge_weight);
+    }
+    (node, cut_weight)
+}
+
+/*
+See https://en.wikipedia.org/wiki/Karger%27s_algorithm
+
+Idea is to uniformly pick an edge to contract, then merge those two nodes,
+and repeat until only two nodes remain. This has a not-too-low chance of finding
+the min-cut, as edges on the min-cut are much rarer than non min-cut edges.
+
+However, it relies on us uniformly picking edges, not nodes, so that heavily connected
+nodes have a greater chance of being contracted. Weighted edges count as that many parallel edges.
+
+See Wikipedia for the probability proof that makes this reasonable.
+ */
+
+// Karger–Stein - optimisation based on most failures happening towards the end of the process, not the start,
+//                so only contract until a 50% chance of failure, then branch into 2 and recurse.
+// Returns the contracted graph, a node whose members form one side of the cut, and the cut's weight.
+fn try_find_min_cut_karger_stein<R: Rng>(mut graph: Contracted, rng: &mut R, good_enough: usize) -> (Contracted, NodeId, usize) {
+    let len = graph.edges.len();
+    if len <= 6 {
+        contract_until_trivial_cut(graph, rng)
+    } else {
+        let mut graph2 = graph.clone();
+        let t = (1.0 + (len as f64) / SQRT_2).ceil() as usize;
+
+        contract_until_size(&mut graph, t, rng);
+        let first = try_find_min_cut_karger_stein(graph, rng, good_enough);
+        if first.2 <= good_enough { return first }
+
+        contract_until_size(&mut graph2, t, rng);
+        let second = try_find_min_cut_karger_stein(graph2, rng, good_enough);
+        if second.2 < first.2 { second } else { first }
+    }
+}
+
+fn contract_until_trivial_cut<R: Rng>(mut graph: Contracted, rng: &mut R) -> (Contracted, NodeId, usize) {
+    contract_until_size(&mut graph, 2, rng);
+    // more than 2 nodes only if the graph is disconnected, in which case a node has no edges to cut
+    let (&side, _) = graph.edges.iter().min_by_key(|(_, edges)| edges.values().sum::<usize>()).unwrap();
+    let weight = graph.edges[&side].values().sum();
+    (graph, side, weight)
+}
+
+fn contract_until_size<R: Rng>(graph: &mut Contracted, min_nodes: usize, rng: &mut R) {
+    while graph.edges.len() > min_nodes {
+        // Need a slightly different structure, to uniformly pick edges
+        // TODO: Could probably optimise by updating this, rather than regenerating each time - e.g. using an adjacency matrix
+        let edges: Vec<(NodeId, NodeId, usize)> = graph.edges.iter()
+            .flat_map(|(i, edges)| edges.iter().filter(move |(j, _)| i < j).map(move |(j, weight)| (*i, *j, *weight)))
+            .collect();
+        let total_weight: usize = edges.iter().map(|(_, _, weight)| weight).sum();
+        if total_weight == 0 { return }
+
+        let mut chosen = rng.gen_range(0..total_weight);
+        let &(s, t, _) = edges.iter().find(|(_, _, weight)| {
+            if chosen < *weight { return true }
+            chosen -= weight;
+            false
+        }).unwrap();
+        graph.merge_nodes(s, t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two triangles of heavy edges, joined by a single light edge from c to d
+    fn two_triangles() -> Graph<char> {
+        let mut graph = Graph::new();
+        for (a, b) in [('a', 'b'), ('b', 'c'), ('c', 'a'), ('d', 'e'), ('e', 'f'), ('f', 'd')] {
+            graph.add_edge(a, b, 3);
+        }
+        graph.add_edge('c', 'd', 1);
+        graph
+    }
+
+    fn sorted_partitions(cut: &Cut<char>) -> [String; 2] {
+        let mut partitions = cut.partitions.clone().map(|mut nodes| { nodes.sort(); nodes.into_iter().collect::<String>() });
+        partitions.sort();
+        partitions
+    }
+
+    #[test]
+    fn repeated_edges_add_weight() {
+        let mut graph = two_triangles();
+        graph.add_edge('d', 'c', 2);
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.edges().count(), 7);
+        assert_eq!(graph.neighbours(&'c').find(|(n, _)| **n == 'd'), Some((&'d', 3)));
+    }
+
+    #[test]
+    fn stoer_wagner_cuts_the_light_edge() {
+        let cut = two_triangles().min_cut_stoer_wagner().unwrap();
+        assert_eq!(cut.weight, 1);
+        assert_eq!(cut.edges.len(), 1);
+        assert_eq!(sorted_partitions(&cut), ["abc", "def"]);
+    }
+
+    #[test]
+    fn find_cut_within_weight() {
+        let graph = two_triangles();
+        assert_eq!(graph.find_cut_stoer_wagner(1).map(|cut| cut.weight), Some(1));
+        assert!(graph.find_cut_stoer_wagner(0).is_none());
+    }
+
+    #[test]
+    fn karger_stein_finds_the_minimum() {
+        let cut = two_triangles().min_cut_karger_stein(1, 20).unwrap();
+        assert_eq!(cut.weight, 1);
+        assert_eq!(sorted_partitions(&cut), ["abc", "def"]);
+    }
+
+    #[test]
+    fn no_cut_of_a_single_node() {
+        let mut graph = Graph::new();
+        graph.add_node('a');
+        assert!(graph.min_cut_stoer_wagner().is_none());
+        assert!(graph.min_cut_karger_stein(1, 1).is_none());
+    }
+
+    #[test]
+    fn st_cut_from_max_flow() {
+        let graph = two_triangles();
+        let cut = graph.min_st_cut(&'a', &'e').unwrap();
+        assert_eq!(cut.weight, 1);
+        assert_eq!(cut.edges, [('c', 'd', 1)]);
+        assert_eq!(sorted_partitions(&cut), ["abc", "def"]);
+        // s is always on the first side
+        assert!(graph.min_st_cut(&'e', &'a').unwrap().partitions[0].contains(&'e'));
+        assert!(graph.min_st_cut(&'a', &'a').is_none());
+        assert!(graph.min_st_cut(&'a', &'z').is_none());
+    }
+}
@@ -1,7 +1,14 @@
-use std::ops::{Index, IndexMut};
+use std::{collections::{HashMap, HashSet}, fmt::Display, ops::{Index, IndexMut}, str::FromStr};
 
-use crate::point::Point;
+use crate::{direction::Direction, error::AocError, input::{InputError, InputSource}, point::Point, union_find::UnionFind};
 
+/// Offsets to the 8 cells around a cell, clockwise from the top left
+const SURROUNDING: [Point; 8] = [
+    Point { x: -1, y: -1 }, Point { x: 0, y: -1 }, Point { x: 1, y: -1 }, Point { x: 1, y: 0 },
+    Point { x: 1, y: 1 }, Point { x: 0, y: 1 }, Point { x: -1, y: 1 }, Point { x: -1, y: 0 },
+];
+
+#[derive(Clone)]
 pub struct Grid<T> {
     cells: Vec<Vec<T>>,
     pub width: i64,
@@ -10,13 +17,35 @@ pub struct Grid<T> {
 
 impl<T> Grid<T> {
     pub fn new(cells: Vec<Vec<T>>) -> Self {
-        let width = cells[0].len();
+        let width = cells.first().map_or(0, Vec::len);
         let height = cells.len();
         Grid { cells, width: width as i64, height: height as i64 }
     }
 
-    pub fn parse<F: FnMut(char) -> T>(lines: impl Iterator<Item=String>, mut f: F) -> Self {
-        Self::new(lines.map(|line| line.chars().map(|c| f(c)).collect()).collect())
+    pub fn parse<F: FnMut(char) -> T>(source: &InputSource, f: F) -> Result<Self, InputError> {
+        Ok(Self::parse_lines(source.lines()?, f))
+    }
+
+    /// As parse, for input that has already been read
+    pub fn parse_lines<F: FnMut(char) -> T>(lines: impl Iterator<Item=String>, mut f: F) -> Self {
+        Self::new(lines.map(|line| line.chars().map(&mut f).collect()).collect())
+    }
+
+    /// As parse_lines, stopping at the first character that f rejects or the first row that's a different
+    /// length to the one before
+    pub fn try_parse_lines<E, F>(lines: impl Iterator<Item=String>, mut f: F) -> Result<Self, AocError>
+        where E: Into<AocError>, F: FnMut(char) -> Result<T, E> {
+        let mut cells: Vec<Vec<T>> = Vec::new();
+        for (y, line) in lines.enumerate() {
+            let row: Vec<T> = line.chars().enumerate()
+                .map(|(x, c)| f(c).map_err(|e| e.into().at_column(x).at_line(y, &line)))
+                .collect::<Result<_, _>>()?;
+            if let Some(width) = cells.last().map(Vec::len).filter(|width| *width != row.len()) {
+                return Err(AocError::new(format!("Expected {width} cells like the row before")).at_line(y, &line))
+            }
+            cells.push(row);
+        }
+        Ok(Self::new(cells))
     }
 
     pub fn is_in_bounds(&self, &Point { x, y }: &Point) -> bool {
@@ -39,6 +68,13 @@ impl<T> Grid<T> {
         )
     }
 
+    /// As enumerate, taking the cells out of the grid
+    pub fn into_enumerate(self) -> impl Iterator<Item=(Point, T)> {
+        self.cells.into_iter().enumerate().flat_map(|(y, row)|
+            row.into_iter().enumerate().map(move |(x, value)| (Point { x: x as i64, y: y as i64 }, value))
+        )
+    }
+
     pub fn map<U, F: FnMut(Point, T) -> U>(self, mut f: F) -> Grid<U> {
         Grid::new(self.cells.into_iter().enumerate()
             .map(|(y, row)| row.into_iter().enumerate()
@@ -47,12 +83,117 @@ impl<T> Grid<T> {
     }
 
     pub fn get(&self, p: &Point) -> Option<&T> {
-        self.cells.get(p.y as usize).map(|row| row.get(p.x as usize)).flatten()
+        self.cells.get(p.y as usize).and_then(|row| row.get(p.x as usize))
     }
 
     pub fn row(&self, row: usize) -> &Vec<T> {
         &self.cells[row]
     }
+
+    /// The cells of column x, from top to bottom
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item=&T> {
+        self.cells.iter().map(move |row| &row[x])
+    }
+
+    /// Displays the grid with each of points drawn as c rather than its cell, e.g. a path through it.
+    /// Further overlays can be added with Highlighted::with, and are drawn over earlier ones.
+    pub fn highlight<'a>(&'a self, points: impl IntoIterator<Item=&'a Point>, c: char) -> Highlighted<'a, T> {
+        Highlighted { grid: self, overlay: HashMap::new() }.with(points, c)
+    }
+
+    /// The width by height rectangle with its top left corner at origin, borrowed from the grid.
+    /// Panics if it doesn't fit inside the grid.
+    pub fn view(&self, origin: Point, width: i64, height: i64) -> GridView<'_, T> {
+        GridView::new(self, origin, width, height)
+    }
+
+    /// The cells directly above, below, left and right of p that are within the grid
+    pub fn neighbours(&self, p: &Point) -> impl Iterator<Item=Point> + '_ {
+        let p = *p;
+        Direction::all().into_iter().map(move |dir| p + dir).filter(|n| self.is_in_bounds(n))
+    }
+
+    /// As neighbours, also including the diagonals
+    pub fn neighbours_with_diagonals(&self, p: &Point) -> impl Iterator<Item=Point> + '_ {
+        let p = *p;
+        SURROUNDING.into_iter().map(move |offset| p + offset).filter(|n| self.is_in_bounds(n))
+    }
+
+    /// The cells matching f that can be reached from seed through neighbours that also match.
+    /// Empty if seed itself doesn't match.
+    pub fn flood_fill<F: FnMut(&T) -> bool>(&self, seed: Point, mut f: F) -> Region {
+        let mut points = HashSet::new();
+        let mut stack = vec![seed];
+        while let Some(p) = stack.pop() {
+            if points.contains(&p) || !self.get(&p).is_some_and(&mut f) { continue }
+            points.insert(p);
+            stack.extend(self.neighbours(&p));
+        }
+        Region { points }
+    }
+
+    /// Every connected region of cells matching f, in the order their first cell is read
+    pub fn regions<F: FnMut(&T) -> bool>(&self, f: F) -> Vec<Region> {
+        let mut regions: Vec<Region> = Vec::new();
+        for (p, label) in self.label_regions(f).enumerate() {
+            let Some(label) = *label else { continue };
+            if label == regions.len() { regions.push(Region::default()) }
+            regions[label].points.insert(p);
+        }
+        regions
+    }
+
+    /// Labels the connected regions of cells matching f, where cells connect to those directly above,
+    /// below, left and right. Regions are numbered from 0 in the order their first cell is read, and
+    /// cells that don't match are None.
+    pub fn label_regions<F: FnMut(&T) -> bool>(&self, f: F) -> Grid<Option<usize>> {
+        let matches: Vec<bool> = self.iter().map(f).collect();
+        let width = self.width as usize;
+        let mut sets = UnionFind::new(matches.len());
+        for i in (0..matches.len()).filter(|i| matches[*i]) {
+            if i % width + 1 < width && matches[i + 1] { sets.union(i, i + 1); }
+            if i + width < matches.len() && matches[i + width] { sets.union(i, i + width); }
+        }
+
+        let mut labels: HashMap<usize, usize> = HashMap::new();
+        let cells: Vec<Option<usize>> = (0..matches.len())
+            .map(|i| matches[i].then(|| {
+                let next_label = labels.len();
+                *labels.entry(sets.find(i)).or_insert(next_label)
+            }))
+            .collect();
+        // by row rather than chunks of width, which would panic on a grid with no columns
+        (0..self.height as usize).map(|y| cells[y * width..(y + 1) * width].to_vec()).collect()
+    }
+}
+
+/// Reordered copies of the grid. Each is its own grid with (0, 0) at the top left, so code written
+/// for one direction can be run on the others, e.g. columns are the rows of the transposed grid.
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, reflecting in the diagonal from the top left
+    pub fn transpose(&self) -> Self {
+        (0..self.width as usize).map(|x| self.column(x).cloned().collect()).collect()
+    }
+
+    /// Turned a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_clockwise(&self) -> Self {
+        (0..self.width as usize).map(|x| self.column(x).rev().cloned().collect()).collect()
+    }
+
+    /// Turned a quarter turn anticlockwise, so the right column becomes the top row
+    pub fn rotate_anticlockwise(&self) -> Self {
+        (0..self.width as usize).rev().map(|x| self.column(x).cloned().collect()).collect()
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(&self) -> Self {
+        self.cells.iter().map(|row| row.iter().rev().cloned().collect()).collect()
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        self.cells.iter().rev().cloned().collect()
+    }
 }
 
 impl<T> Index<&Point> for Grid<T> {
@@ -69,8 +210,323 @@ impl<T> IndexMut<&Point> for Grid<T> {
     }
 }
 
+/// A set of cells in a grid, e.g. from Grid::flood_fill
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Region {
+    points: HashSet<Point>,
+}
+
+impl Region {
+    pub fn points(&self) -> &HashSet<Point> {
+        &self.points
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.points.contains(p)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of cell sides between the region and cells outside it, including the edge of the grid
+    pub fn perimeter(&self) -> usize {
+        self.points.iter()
+            .map(|p| Direction::all().into_iter().filter(|dir| !self.points.contains(&(*p + *dir))).count())
+            .sum()
+    }
+}
+
+/// Cells that can be drawn as a single character, normally the one they're parsed from
+pub trait GridCell {
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl<T: GridCell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.highlight([], ' ').fmt(f)
+    }
+}
+
+/// One line per row, as Grid::try_parse_lines
+impl<T: TryFrom<char>> FromStr for Grid<T> where T::Error: Into<AocError> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.lines().next().is_none_or(str::is_empty) { return Err(AocError::new("Empty grid")) }
+        Self::try_parse_lines(s.lines().map(String::from), T::try_from)
+    }
+}
+
+/// A grid displayed with some of its cells drawn as other characters, from Grid::highlight
+pub struct Highlighted<'a, T> {
+    grid: &'a Grid<T>,
+    overlay: HashMap<Point, char>,
+}
+
+impl<'a, T> Highlighted<'a, T> {
+    /// Also draws each of points as c. Points outside the grid are ignored.
+    pub fn with(mut self, points: impl IntoIterator<Item=&'a Point>, c: char) -> Self {
+        self.overlay.extend(points.into_iter().map(|p| (*p, c)));
+        self
+    }
+}
+
+impl<T: GridCell> Display for Highlighted<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.cells.iter().enumerate() {
+            if y > 0 { writeln!(f)? }
+            for (x, cell) in row.iter().enumerate() {
+                let p = Point { x: x as i64, y: y as i64 };
+                write!(f, "{}", self.overlay.get(&p).copied().unwrap_or_else(|| cell.to_char()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<T> FromIterator<Vec<T>> for Grid<T> {
     fn from_iter<TIter: IntoIterator<Item = Vec<T>>>(iter: TIter) -> Self {
         Grid::new(iter.into_iter().collect())
     }
-}
\ No newline at end of file
+}
+
+/// A rectangle of cells borrowed from a grid, with its own coordinates starting at (0, 0) in its top left corner
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    fn new(grid: &'a Grid<T>, origin: Point, width: i64, height: i64) -> Self {
+        assert!(width >= 0 && height >= 0, "View can't have a negative size");
+        assert!(origin.x >= 0 && origin.y >= 0 && origin.x + width <= grid.width && origin.y + height <= grid.height,
+            "View must fit inside the grid");
+        GridView { grid, origin, width, height }
+    }
+
+    /// Where the view's (0, 0) is in the underlying grid
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn is_in_bounds(&self, &Point { x, y }: &Point) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&'a T> {
+        self.is_in_bounds(p).then(|| &self.grid[&(*p + self.origin)])
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        let start = self.origin.x as usize;
+        &self.grid.row(self.origin.y as usize + row)[start..start + self.width as usize]
+    }
+
+    /// The cells of column x, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&'a T> + 'a {
+        let view = *self;
+        (0..self.height as usize).map(move |y| &view.row(y)[x])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&'a T> + 'a {
+        let view = *self;
+        (0..self.height as usize).flat_map(move |y| view.row(y).iter())
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item=(Point, &'a T)> + 'a {
+        let view = *self;
+        (0..self.height as usize).flat_map(move |y| view.row(y).iter().enumerate()
+            .map(move |(x, value)| (Point { x: x as i64, y: y as i64 }, value)))
+    }
+
+    /// A smaller view, with origin relative to this one
+    pub fn view(&self, origin: Point, width: i64, height: i64) -> GridView<'a, T> {
+        assert!(width >= 0 && height >= 0, "View can't have a negative size");
+        assert!(origin.x >= 0 && origin.y >= 0 && origin.x + width <= self.width && origin.y + height <= self.height,
+            "View must fit inside the view");
+        GridView::new(self.grid, origin + self.origin, width, height)
+    }
+
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        (0..self.height as usize).map(|y| self.row(y).to_vec()).collect()
+    }
+}
+
+impl<T> Index<&Point> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &Self::Output {
+        self.get(point).expect("Point outside the view")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        "ab.\n.b.\ncc#".parse().unwrap()
+    }
+
+    enum Cell { Open, Wall }
+
+    impl TryFrom<char> for Cell {
+        type Error = AocError;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Cell::Open),
+                '#' => Ok(Cell::Wall),
+                _ => Err(AocError::invalid(c, "Unrecognised cell")),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "..#\n.#.\n.#".parse::<Grid<Cell>>().err().unwrap();
+        assert_eq!((error.line, error.column), (Some(3), None));
+        assert_eq!(error.to_string(), "line 3: Expected 3 cells like the row before \".#\"");
+
+        let error = "..#\n.x.".parse::<Grid<Cell>>().err().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        assert_eq!(error.to_string(), "line 2, column 2: Unrecognised cell \"x\"");
+
+        assert_eq!("".parse::<Grid<Cell>>().err().unwrap().to_string(), "Empty grid");
+        let grid: Grid<Cell> = "..#\n.#.".parse().unwrap();
+        assert!(matches!(grid[&Point { x: 2, y: 0 }], Cell::Wall));
+    }
+
+    #[test]
+    fn nested_views() {
+        let grid = example();
+        let view = grid.view(Point { x: 1, y: 1 }, 2, 2);
+        assert_eq!(view.to_grid().to_string(), "b.\nc#");
+        assert_eq!(view.view(Point { x: 1, y: 0 }, 1, 2).to_grid().to_string(), ".\n#");
+    }
+
+    #[test]
+    #[should_panic(expected = "View must fit inside the view")]
+    fn view_before_origin() {
+        let grid = example();
+        grid.view(Point { x: 1, y: 1 }, 2, 2).view(Point { x: -1, y: 0 }, 1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "View can't have a negative size")]
+    fn view_with_negative_size() {
+        let grid = example();
+        grid.view(Point { x: 1, y: 1 }, 2, 2).view(Point { x: 1, y: 1 }, -1, 1);
+    }
+
+    fn points(points: &[(i64, i64)]) -> HashSet<Point> {
+        points.iter().map(|&(x, y)| Point { x, y }).collect()
+    }
+
+    #[test]
+    fn neighbours_within_grid() {
+        let grid = example();
+        let neighbours = |x, y| grid.neighbours(&Point { x, y }).collect::<HashSet<_>>();
+        assert_eq!(neighbours(0, 0), points(&[(1, 0), (0, 1)]));
+        assert_eq!(neighbours(1, 0), points(&[(0, 0), (2, 0), (1, 1)]));
+        assert_eq!(neighbours(1, 1).len(), 4);
+
+        let with_diagonals = |x, y| grid.neighbours_with_diagonals(&Point { x, y }).collect::<HashSet<_>>();
+        assert_eq!(with_diagonals(2, 2), points(&[(1, 1), (2, 1), (1, 2)]));
+        assert_eq!(with_diagonals(0, 1), points(&[(0, 0), (1, 0), (1, 1), (1, 2), (0, 2)]));
+        assert_eq!(with_diagonals(1, 1).len(), 8);
+    }
+
+    #[test]
+    fn flood_fills() {
+        let grid = example();
+        let region = grid.flood_fill(Point { x: 0, y: 0 }, |c| ['a', 'b'].contains(c));
+        assert_eq!(*region.points(), points(&[(0, 0), (1, 0), (1, 1)]));
+        assert_eq!((region.area(), region.perimeter()), (3, 8));
+        assert!(grid.flood_fill(Point { x: 2, y: 0 }, |c| *c == 'b').is_empty());
+    }
+
+    #[test]
+    fn regions_in_reading_order() {
+        let regions = example().regions(|c| *c == '.');
+        assert_eq!(regions.iter().map(|region| region.points().clone()).collect::<Vec<_>>(),
+            [points(&[(2, 0), (2, 1)]), points(&[(0, 1)])]);
+        assert_eq!(regions.iter().map(|region| (region.area(), region.perimeter())).collect::<Vec<_>>(), [(2, 6), (1, 4)]);
+    }
+
+    #[test]
+    fn labels_regions() {
+        let labels = example().label_regions(|c| *c != '.');
+        assert_eq!(labels.iter().copied().collect::<Vec<_>>(),
+            [Some(0), Some(0), None, None, Some(0), None, Some(0), Some(0), Some(0)]);
+        let regions = example().regions(|c| *c != '.');
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].area(), 6);
+    }
+
+    #[test]
+    fn column() {
+        let grid = example();
+        assert_eq!(grid.column(1).collect::<String>(), "bbc");
+        assert_eq!(grid.column(2).rev().collect::<String>(), "#..");
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "a.c\nbbc\n..#");
+        assert_eq!(grid.rotate_clockwise().to_string(), "c.a\ncbb\n#..");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "..#\nbbc\na.c");
+        assert_eq!(grid.flip_horizontal().to_string(), ".ba\n.b.\n#cc");
+        assert_eq!(grid.flip_vertical().to_string(), "cc#\n.b.\nab.");
+    }
+
+    #[test]
+    fn transforms_swap_width_and_height() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        for (turned, expected) in [(grid.transpose(), "ad\nbe\ncf"), (grid.rotate_clockwise(), "da\neb\nfc"), (grid.rotate_anticlockwise(), "cf\nbe\nad")] {
+            assert_eq!((turned.width, turned.height), (2, 3));
+            assert_eq!(turned.to_string(), expected);
+        }
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn empty_grids() {
+        let grid: Grid<char> = Vec::new().into_iter().collect();
+        assert_eq!((grid.width, grid.height), (0, 0));
+        let without_columns: Grid<char> = Grid::new(vec![vec![], vec![]]);
+        assert_eq!((without_columns.transpose().width, without_columns.transpose().height), (0, 0));
+        assert_eq!(without_columns.rotate_clockwise().height, 0);
+        assert_eq!(example().view(Point { x: 1, y: 1 }, 2, 0).to_grid().height, 0);
+    }
+
+    #[test]
+    fn labels_regions_without_columns() {
+        let grid: Grid<char> = Grid::new(vec![vec![], vec![]]);
+        let labels = grid.label_regions(|_| true);
+        assert_eq!((labels.width, labels.height), (0, 2));
+    }
+}
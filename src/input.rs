@@ -0,0 +1,105 @@
+use std::{fmt::Display, fs, io::{self, Read}, path::PathBuf};
+
+/// Environment variable overriding the directory that day inputs (and their recorded answers) are read from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "resources";
+
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    /// day{N}.txt within the input directory, see input_dir()
+    Day(u32),
+    Path(PathBuf),
+    Stdin,
+    /// Input held in memory, mainly for testing against puzzle examples
+    Text(String),
+}
+
+impl InputSource {
+    /// Reads the whole input up front, so that any IO or UTF-8 error is reported here
+    /// rather than part way through a solution.
+    pub fn lines(&self) -> Result<impl Iterator<Item=String>, InputError> {
+        let contents = match self {
+            InputSource::Day(day) => read_file(day_path(*day))?,
+            InputSource::Path(path) => read_file(path.clone())?,
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map_err(|error| InputError { path: None, error })?;
+                contents
+            },
+            InputSource::Text(text) => text.clone(),
+        };
+        Ok(contents.lines().map(String::from).collect::<Vec<_>>().into_iter())
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(text: &str) -> Self {
+        InputSource::Text(String::from(text))
+    }
+}
+
+/// AOC_INPUT_DIR if set, otherwise 'resources' relative to the working directory
+pub fn input_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from)
+}
+
+pub fn day_path(day: u32) -> PathBuf {
+    input_dir().join(format!("day{day}.txt"))
+}
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|error| InputError { path: Some(path), error })
+}
+
+/// Failure to read an input, along with the path that was attempted (None when reading stdin)
+#[derive(Debug)]
+pub struct InputError {
+    pub path: Option<PathBuf>,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "Couldn't read {}: {}", path.display(), self.error),
+            None => write!(f, "Couldn't read stdin: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::error::AocError;
+
+    use super::*;
+
+    #[test]
+    fn text_lines() {
+        let source = InputSource::from("a b\r\n\nc\n");
+        assert_eq!(source.lines().unwrap().collect::<Vec<_>>(), ["a b", "", "c"]);
+        // held in memory, so it can be read again for the other part
+        assert_eq!(source.lines().unwrap().count(), 3);
+        assert_eq!(InputSource::from("").lines().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn missing_file() {
+        let path = PathBuf::from("no/such/dir/day1.txt");
+        let error = InputSource::Path(path.clone()).lines().err().unwrap();
+        assert_eq!(error.path, Some(path));
+        assert_eq!(error.error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with("Couldn't read no/such/dir/day1.txt: "));
+
+        // the io::Error is still available once it's an AocError
+        let error = AocError::from(error);
+        assert!(error.source().is_some_and(|source| source.is::<InputError>()));
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fmt::Debug, ops::Range};
+
+use num::PrimInt;
+
+/// A set of integers, stored as sorted half-open ranges that don't overlap or touch
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// The ranges making up the set, in increasing order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::zero(), |total, range| total + (range.end - range.start))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// Largest value in the set, rather than the end of the last range
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // first range that ends after value, which is the only one that could contain it
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() { return }
+        // ranges that overlap or touch the new one are merged into it
+        let first = self.ranges.partition_point(|existing| existing.end < range.start);
+        let last = self.ranges.partition_point(|existing| existing.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        *self = self.difference(&range.into());
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(other.ranges.iter()).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() { ranges.push(overlap) }
+            // whichever finishes first can't overlap anything else
+            if a.end <= b.end { i += 1 } else { j += 1 }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.min(), self.ranges.last()) {
+            (Some(start), Some(last)) => self.intersection(&other.complement(start..last.end)),
+            _ => Self::new(),
+        }
+    }
+
+    /// Values within bound that aren't in the set
+    pub fn complement(&self, bound: Range<T>) -> Self {
+        let mut ranges = Vec::new();
+        let mut start = bound.start;
+        for range in &self.ranges {
+            let gap = start..range.start.min(bound.end);
+            if !gap.is_empty() { ranges.push(gap) }
+            start = start.max(range.end);
+        }
+        if start < bound.end { ranges.push(start..bound.end) }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+/// Ranges can be in any order, and overlap
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Range<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().filter(|range| !range.is_empty()).collect();
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn inserts_merge_touching_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(5..8);
+        set.insert(0..2);
+        set.insert(8..10);
+        set.insert(3..3);
+        assert_eq!(set.ranges(), [0..2, 5..10]);
+        set.insert(1..6);
+        assert_eq!(set, IntervalSet::from(0..10));
+        assert_eq!((set.len(), set.min(), set.max()), (10, Some(0), Some(9)));
+    }
+
+    #[test]
+    fn collects_overlapping_ranges() {
+        let set: IntervalSet<i32> = [(4..6), (-3..1), (0..2), (5..5), (6..7)].into_iter().collect();
+        assert_eq!(set.ranges(), [-3..2, 4..7]);
+        assert!(set.contains(-3) && set.contains(6) && !set.contains(2) && !set.contains(7));
+    }
+
+    #[test]
+    fn difference_splits_ranges() {
+        let set: IntervalSet<u32> = [(0..10), (20..30)].into_iter().collect();
+        let removed: IntervalSet<u32> = [(3..5), (8..22), (29..40)].into_iter().collect();
+        assert_eq!(set.difference(&removed).ranges(), [0..3, 5..8, 22..29]);
+        assert_eq!(removed.difference(&set).ranges(), [10..20, 30..40]);
+        assert!(set.difference(&set).is_empty());
+        let mut removing = set.clone();
+        removing.remove(5..25);
+        assert_eq!(removing.ranges(), [0..5, 25..30]);
+    }
+
+    #[test]
+    fn complement_within_bound() {
+        let set: IntervalSet<u8> = [(2..4), (6..8), (20..30)].into_iter().collect();
+        assert_eq!(set.complement(3..10).ranges(), [4..6, 8..10]);
+        assert_eq!(IntervalSet::new().complement(0..5), IntervalSet::from(0..5));
+    }
+
+    #[test]
+    fn matches_set_of_values() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut random_set = || -> IntervalSet<u32> {
+            (0..4).map(|_| { let start = rng.gen_range(0..40); start..start + rng.gen_range(0..10) }).collect()
+        };
+        let values = |set: &IntervalSet<u32>| (0..50).filter(|n| set.contains(*n)).collect::<BTreeSet<_>>();
+        for _ in 0..200 {
+            let (a, b) = (random_set(), random_set());
+            let (a_values, b_values) = (values(&a), values(&b));
+            assert_eq!(values(&a.union(&b)), &a_values | &b_values);
+            assert_eq!(values(&a.intersection(&b)), &a_values & &b_values);
+            assert_eq!(values(&a.difference(&b)), &a_values - &b_values);
+            assert_eq!(a.len() as usize, a_values.len());
+        }
+    }
+}
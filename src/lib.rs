@@ -1,28 +1,71 @@
 
-use std::{
-    collections::{BinaryHeap, HashMap, VecDeque}, fs::File, io::{BufRead, BufReader}
-};
+use std::{cmp::{max, min, Ordering}, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash, str::FromStr};
 
 pub mod point;
 pub mod point3;
+pub mod polygon;
+pub mod range_map;
+pub mod cuboid;
+pub mod cycle;
 pub mod direction;
+pub mod dominators;
+pub mod error;
+pub mod flow;
+pub mod graph;
 pub mod grid;
-
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod periodic;
+pub mod solution;
+pub mod sparse_grid;
+pub mod tiled_grid;
+pub mod union_find;
+pub mod days;
+pub mod verify;
+
+use error::AocError;
+use input::{InputError, InputSource};
 use point::Point;
 
-pub fn read_input(day: u32) -> impl Iterator<Item=String> {
-    let filename = format!("resources/day{day}.txt");
-    let f = File::open(&filename).expect(format!("Couldn't open {filename}").as_str());
-    BufReader::new(f).lines().map(Result::unwrap)
+/// Reads day{day}.txt from the input directory, see input::input_dir()
+pub fn read_input(day: u32) -> Result<impl Iterator<Item=String>, InputError> {
+    InputSource::Day(day).lines()
 }
 
 /// f(point, character) for each cell of the grid, with the first character in the top left being Point { x: 0, y: 0 }
-pub fn process_grid<F: FnMut(Point, char)>(day: u32, mut f: F) {
-    read_input(day).enumerate().for_each(|(y, line)| line.chars().enumerate().for_each(|(x, c)|
+pub fn process_grid<F: FnMut(Point, char)>(source: &InputSource, f: F) -> Result<(), InputError> {
+    process_grid_lines(source.lines()?, f);
+    Ok(())
+}
+
+/// As process_grid, for input that has already been read
+pub fn process_grid_lines<F: FnMut(Point, char)>(lines: impl Iterator<Item=String>, mut f: F) {
+    lines.enumerate().for_each(|(y, line)| line.chars().enumerate().for_each(|(x, c)|
         f(Point { x: x.try_into().unwrap(), y: y.try_into().unwrap() }, c)
     ));
 }
 
+/// As process_grid_lines, stopping at the first cell that f rejects
+pub fn try_process_grid_lines<E, F>(lines: impl Iterator<Item=String>, mut f: F) -> Result<(), AocError>
+    where E: Into<AocError>, F: FnMut(Point, char) -> Result<(), E> {
+    for (y, line) in lines.enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            f(Point { x: x.try_into().unwrap(), y: y.try_into().unwrap() }, c)
+                .map_err(|e| e.into().at_column(x).at_line(y, &line))?;
+        }
+    }
+    Ok(())
+}
+
+/// Parses each line with FromStr, reporting the first line that fails
+pub fn parse_lines<T>(lines: impl Iterator<Item=String>) -> Result<Vec<T>, AocError>
+    where T: FromStr, T::Err: Into<AocError> {
+    lines.enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: T::Err| e.into().at_line(i, &line)))
+        .collect()
+}
+
 pub fn split_in_two(s: &str, separator: char) -> (&str, &str) {
     let split: Vec<_> = s.split(separator).collect();
     assert!(split.len() == 2);
@@ -55,6 +98,87 @@ pub trait BreadthFirstSearch : Sized {
             }
         }
     }
+
+    /// Distance to, and the parent of, every node reachable from start
+    fn search_tree(mut self, start: Self::Node) -> BfsTree<Self::Node> where Self::Node: Clone + Eq + Hash {
+        bfs_tree(&mut self, start, |_| false).0
+    }
+
+    /// Shortest path from start to the first node found that is a goal, stopping the search there
+    fn search_until<F: FnMut(&Self::Node) -> bool>(mut self, start: Self::Node, is_goal: F) -> Option<Vec<Self::Node>>
+        where Self::Node: Clone + Eq + Hash {
+        let (tree, goal) = bfs_tree(&mut self, start, is_goal);
+        goal.and_then(|goal| tree.path_to(&goal))
+    }
+
+    /// Nodes at exactly 0, 1, 2... steps from start, only exploring as far as is iterated
+    fn layers(mut self, start: Self::Node) -> BfsLayers<Self> {
+        assert!(self.mark(&start));
+        BfsLayers { search: self, frontier: vec![start] }
+    }
+}
+
+pub struct BfsTree<T> {
+    pub distances: HashMap<T, usize>,
+    /// Every reached node except the start
+    pub parents: HashMap<T, T>,
+}
+
+impl<T: Clone + Eq + Hash> BfsTree<T> {
+    /// From the start to the node inclusive, None if the node wasn't reached
+    pub fn path_to(&self, node: &T) -> Option<Vec<T>> {
+        if !self.distances.contains_key(node) { return None }
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+fn bfs_tree<S, F>(search: &mut S, start: S::Node, mut is_goal: F) -> (BfsTree<S::Node>, Option<S::Node>)
+    where S: BreadthFirstSearch, S::Node: Clone + Eq + Hash, F: FnMut(&S::Node) -> bool {
+    let mut tree = BfsTree { distances: HashMap::new(), parents: HashMap::new() };
+    let mut to_process = VecDeque::new();
+
+    assert!(search.mark(&start));
+    tree.distances.insert(start.clone(), 0);
+    if is_goal(&start) { return (tree, Some(start)) }
+    to_process.push_back(start);
+
+    while let Some(node) = to_process.pop_front() {
+        let distance = tree.distances[&node] + 1;
+        for next in search.neighbours(&node) {
+            if search.mark(&next) {
+                tree.distances.insert(next.clone(), distance);
+                tree.parents.insert(next.clone(), node.clone());
+                if is_goal(&next) { return (tree, Some(next)) }
+                to_process.push_back(next);
+            }
+        }
+    }
+    (tree, None)
+}
+
+pub struct BfsLayers<S: BreadthFirstSearch> {
+    search: S,
+    frontier: Vec<S::Node>,
+}
+
+impl<S: BreadthFirstSearch> Iterator for BfsLayers<S> {
+    type Item = Vec<S::Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frontier.is_empty() { return None }
+        let mut next_frontier = Vec::new();
+        for node in &self.frontier {
+            for next in self.search.neighbours(node) {
+                if self.search.mark(&next) { next_frontier.push(next) }
+            }
+        }
+        Some(std::mem::replace(&mut self.frontier, next_frontier))
+    }
 }
 
 pub trait Dijkstra: Sized {
@@ -64,26 +188,179 @@ pub trait Dijkstra: Sized {
 
     fn neighbours(&self, value: &Self::State) -> Vec<(usize, Self::State)>;
 
+    /// Whether cost is the cheapest way found to state so far, recording it if so.
+    /// Every search first calls it for each start with cost 0, so that starts are never revisited.
     fn try_improve(&mut self, state: &Self::State, cost: usize) -> bool;
 
-    fn search(mut self, starts: Vec<Self::State>) -> usize {
+    /// Panics if no end state is reachable, see try_search
+    fn search(self, starts: Vec<Self::State>) -> usize {
+        self.try_search(starts).expect("No end state is reachable from the start states")
+    }
+
+    /// Cost of the cheapest path to an end state, or None if no end state is reachable
+    fn try_search(self, starts: Vec<Self::State>) -> Option<usize> {
+        self.search_with_stats(starts).cost
+    }
+
+    fn search_with_stats(mut self, starts: Vec<Self::State>) -> SearchStats {
+        best_first_search(&mut self, starts, |_, _| 0)
+    }
+
+    /// As try_search, also recording each state's predecessor so that the cheapest path can be recovered.
+    fn search_path(mut self, starts: Vec<Self::State>) -> Option<DijkstraPath<Self::State>>
+        where Self::State: Clone + Eq + Hash {
         let mut to_explore: BinaryHeap<DijkstraCost<Self::State>> = BinaryHeap::new();
+        let mut previous: HashMap<Self::State, Self::State> = HashMap::new();
         for start in starts {
-            to_explore.push(DijkstraCost {value: start, cost: 0});
+            if self.try_improve(&start, 0) { to_explore.push(DijkstraCost {value: start, cost: 0}) }
         }
 
-        loop {
-            let DijkstraCost { cost, value } = to_explore.pop().unwrap();
-            if self.is_end(&value) { return cost }
+        while let Some(DijkstraCost { cost, value }) = to_explore.pop() {
+            if self.is_end(&value) {
+                let mut states = vec![value];
+                while let Some(state) = previous.get(states.last().unwrap()) {
+                    states.push(state.clone());
+                }
+                states.reverse();
+                return Some(DijkstraPath { cost, states })
+            }
 
             for (added_cost, new_value) in self.neighbours(&value) {
                 let new_cost = cost + added_cost;
                 if self.try_improve(&new_value, new_cost) {
+                    previous.insert(new_value.clone(), value.clone());
                     to_explore.push(DijkstraCost { cost: new_cost, value: new_value });
                 }
             }
         }
+        None
     }
+
+    /// Every cheapest path to any end state, empty if no end state is reachable.
+    /// A state reached again at its best cost, which try_improve rejects, is still recorded as an
+    /// alternative route to it. The number of paths can grow exponentially with the number of ties.
+    /// Paths never repeat a state, so any zero-cost cycles are left out of them.
+    fn search_all_paths(mut self, starts: Vec<Self::State>) -> Vec<DijkstraPath<Self::State>>
+        where Self::State: Clone + Eq + Hash {
+        let mut to_explore: BinaryHeap<DijkstraCost<Self::State>> = BinaryHeap::new();
+        let mut best_costs: HashMap<Self::State, usize> = HashMap::new();
+        let mut previous: HashMap<Self::State, Vec<Self::State>> = HashMap::new();
+        for start in starts {
+            if self.try_improve(&start, 0) {
+                best_costs.insert(start.clone(), 0);
+                to_explore.push(DijkstraCost {value: start, cost: 0});
+            }
+        }
+
+        let mut end_cost = None;
+        let mut ends = Vec::new();
+        while let Some(DijkstraCost { cost, value }) = to_explore.pop() {
+            if end_cost.is_some_and(|end_cost| cost > end_cost) { break }
+            // stale entry, a cheaper way to this state has already been explored
+            if best_costs.get(&value).is_some_and(|best| cost > *best) { continue }
+            if self.is_end(&value) {
+                end_cost = Some(cost);
+                ends.push(value);
+                continue
+            }
+
+            for (added_cost, new_value) in self.neighbours(&value) {
+                let new_cost = cost + added_cost;
+                if best_costs.get(&new_value) == Some(&new_cost) {
+                    // starts have no predecessors, even if a zero-cost edge leads back to one
+                    if let Some(predecessors) = previous.get_mut(&new_value) {
+                        predecessors.push(value.clone());
+                    }
+                } else if self.try_improve(&new_value, new_cost) {
+                    best_costs.insert(new_value.clone(), new_cost);
+                    previous.insert(new_value.clone(), vec![value.clone()]);
+                    to_explore.push(DijkstraCost { cost: new_cost, value: new_value });
+                }
+            }
+        }
+
+        let Some(cost) = end_cost else { return Vec::new() };
+        // walk back from each end, every state without a predecessor being a start
+        let mut paths = Vec::new();
+        let mut partial_paths: Vec<Vec<Self::State>> = ends.into_iter().map(|end| vec![end]).collect();
+        while let Some(path) = partial_paths.pop() {
+            match previous.get(path.last().unwrap()) {
+                None => {
+                    let mut states = path;
+                    states.reverse();
+                    paths.push(DijkstraPath { cost, states });
+                },
+                Some(predecessors) => for predecessor in predecessors.iter().filter(|p| !path.contains(p)) {
+                    let mut path = path.clone();
+                    path.push(predecessor.clone());
+                    partial_paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// Dijkstra, guided towards the end by an estimate of the remaining cost from each state
+pub trait AStar: Dijkstra {
+    /// Must never overestimate the cheapest remaining cost to an end state, otherwise the search may
+    /// settle for a more expensive path. 0 everywhere is the same as Dijkstra.
+    fn heuristic(&self, state: &Self::State) -> usize;
+
+    fn a_star_search(mut self, starts: Vec<Self::State>) -> SearchStats {
+        best_first_search(&mut self, starts, Self::heuristic)
+    }
+}
+
+/// Manhattan distance to the end, scaled by the cheapest cost of a single orthogonal step
+pub fn manhattan_heuristic(p: &Point, end: &Point, min_step_cost: usize) -> usize {
+    p.orthogonal_distance(end) as usize * min_step_cost
+}
+
+/// As manhattan_heuristic, for whichever end is closest
+pub fn manhattan_heuristic_to_nearest(p: &Point, ends: &[Point], min_step_cost: usize) -> usize {
+    ends.iter().map(|end| manhattan_heuristic(p, end, min_step_cost)).min().unwrap_or(0)
+}
+
+/// Result of a Dijkstra or A* search, with the number of states taken off the queue and explored
+#[derive(Debug, Clone, Copy)]
+pub struct SearchStats {
+    /// None if no end state is reachable
+    pub cost: Option<usize>,
+    pub expanded: usize,
+}
+
+fn best_first_search<S: Dijkstra, H: Fn(&S, &S::State) -> usize>(search: &mut S, starts: Vec<S::State>, heuristic: H) -> SearchStats {
+    // ordered by estimated total cost, holding the actual cost so far alongside the state
+    let mut to_explore: BinaryHeap<DijkstraCost<(usize, S::State)>> = BinaryHeap::new();
+    for start in starts {
+        if search.try_improve(&start, 0) {
+            let estimate = heuristic(search, &start);
+            to_explore.push(DijkstraCost {value: (0, start), cost: estimate});
+        }
+    }
+
+    let mut expanded = 0;
+    while let Some(DijkstraCost { value: (cost, value), .. }) = to_explore.pop() {
+        if search.is_end(&value) { return SearchStats { cost: Some(cost), expanded } }
+        expanded += 1;
+
+        for (added_cost, new_value) in search.neighbours(&value) {
+            let new_cost = cost + added_cost;
+            if search.try_improve(&new_value, new_cost) {
+                let estimate = new_cost + heuristic(search, &new_value);
+                to_explore.push(DijkstraCost { cost: estimate, value: (new_cost, new_value) });
+            }
+        }
+    }
+    SearchStats { cost: None, expanded }
+}
+
+/// States from a start to an end inclusive, and the total cost of moving between them
+#[derive(Debug, Clone)]
+pub struct DijkstraPath<T> {
+    pub cost: usize,
+    pub states: Vec<T>,
 }
 
 #[derive(Debug)]
@@ -115,9 +392,13 @@ impl<T> PartialEq for DijkstraCost<T> {
 impl<T> Eq for DijkstraCost<T> {}
 
 
+#[derive(Debug)]
 pub struct GraphContainsCycleError<T> {
+    /// The edge from -> to closes the cycle
     pub from: T,
     pub to: T,
+    /// Every node of the cycle in edge order, starting at 'to' and ending at 'from'
+    pub cycle: Vec<T>,
 }
 
 pub trait TopologicalSort {
@@ -126,30 +407,42 @@ pub trait TopologicalSort {
     fn get_all_nodes(&self) -> Vec<&Self::Node>;
     fn get_edges(&self, node: &Self::Node) -> Vec<&Self::Node>;
 
+    /// Depth first, so the order depends on the order of get_all_nodes and get_edges
     fn sort(&mut self) -> Result<Vec<&Self::Node>, GraphContainsCycleError<&Self::Node>> {
         let mut reverse_sorted = Vec::new();
         let mut nodes_seen = HashMap::new();
+        // DFS tree, so that the path round any cycle found can be recovered
+        let mut parents = HashMap::new();
     
         for node in self.get_all_nodes() {
             if let Some(TopologicalSortState::Visited) = nodes_seen.get(node) { continue; }
     
             nodes_seen.insert(node, TopologicalSortState::ToVisit);
-            let mut stack = vec![node];
+            let mut stack = vec![(node, None)];
             
-            while let Some(node) = stack.pop() {
+            while let Some((node, parent)) = stack.pop() {
                 match nodes_seen.get_mut(&node) {
                     Some(state @ TopologicalSortState::ToVisit) => {
                         *state = TopologicalSortState::Visiting;
+                        if let Some(parent) = parent { parents.insert(node, parent); }
                         let edges = self.get_edges(node);
-                        stack.push(node);
+                        stack.push((node, parent));
                         for next in edges {
                             match nodes_seen.get(next) {
                                 None => {
                                     nodes_seen.insert(next, TopologicalSortState::ToVisit);
-                                    stack.push(next)
+                                    stack.push((next, Some(node)))
+                                },
+                                Some(TopologicalSortState::ToVisit) => stack.push((next, Some(node))), // DFS found it via a different branch higher up too, eagerly explore
+                                Some(TopologicalSortState::Visiting) => {
+                                    // every node being visited is on the DFS path to here, which must lead back to 'next'
+                                    let mut cycle = vec![node];
+                                    while *cycle.last().unwrap() != next {
+                                        cycle.push(parents[cycle.last().unwrap()]);
+                                    }
+                                    cycle.reverse();
+                                    return Err(GraphContainsCycleError { from: node, to: next, cycle })
                                 },
-                                Some(TopologicalSortState::ToVisit) => stack.push(next), // DFS found it via a different branch higher up too, eagerly explore
-                                Some(TopologicalSortState::Visiting) => return Err(GraphContainsCycleError { from: node, to: next }),
                                 Some(TopologicalSortState::Visited) => {},
                             }
                         }
@@ -166,66 +459,474 @@ pub trait TopologicalSort {
         reverse_sorted.reverse();
         Ok(reverse_sorted)
     }
+
+    /// Kahn's algorithm, repeatedly taking the smallest node (according to tie_break) of those with no
+    /// remaining incoming edges, so the order is reproducible regardless of the order of get_all_nodes.
+    fn sort_kahn<F>(&self, mut tie_break: F) -> Result<Vec<&Self::Node>, GraphContainsCycleError<&Self::Node>>
+        where F: FnMut(&Self::Node, &Self::Node) -> Ordering {
+        let nodes = self.get_all_nodes();
+        let mut in_degrees: HashMap<&Self::Node, usize> = nodes.iter().map(|node| (*node, 0)).collect();
+        for node in &nodes {
+            for next in self.get_edges(node) {
+                *in_degrees.entry(next).or_default() += 1;
+            }
+        }
+
+        let mut ready: Vec<&Self::Node> = Vec::new();
+        for (node, _) in in_degrees.iter().filter(|(_, in_degree)| **in_degree == 0) {
+            insert_sorted(&mut ready, node, &mut tie_break);
+        }
+
+        let mut sorted = Vec::with_capacity(in_degrees.len());
+        while let Some(node) = ready.pop() {
+            sorted.push(node);
+            for next in self.get_edges(node) {
+                let in_degree = in_degrees.get_mut(next).unwrap();
+                *in_degree -= 1;
+                if *in_degree == 0 { insert_sorted(&mut ready, next, &mut tie_break) }
+            }
+        }
+
+        if sorted.len() < in_degrees.len() {
+            let remaining = in_degrees.into_iter().filter(|(_, in_degree)| *in_degree > 0).map(|(node, _)| node).collect();
+            return Err(find_cycle(self, remaining).expect("Bug, Kahn's algorithm left nodes that aren't on or after a cycle"))
+        }
+        Ok(sorted)
+    }
+
+    /// Nodes grouped by the length of the longest path to them from a node with no incoming edges,
+    /// so that every edge goes from an earlier layer to a later one. Layers are in get_all_nodes order.
+    fn layers(&self) -> Result<Vec<Vec<&Self::Node>>, GraphContainsCycleError<&Self::Node>> {
+        let order: HashMap<&Self::Node, usize> = self.get_all_nodes().into_iter().enumerate().map(|(i, node)| (node, i)).collect();
+        let sorted = self.sort_kahn(|a, b| order.get(a).cmp(&order.get(b)))?;
+
+        let mut depths: HashMap<&Self::Node, usize> = HashMap::new();
+        let mut layers: Vec<Vec<&Self::Node>> = Vec::new();
+        for node in sorted {
+            let depth = *depths.entry(node).or_default();
+            if depth == layers.len() { layers.push(Vec::new()) }
+            layers[depth].push(node);
+            for next in self.get_edges(node) {
+                let next_depth = depths.entry(next).or_default();
+                *next_depth = max(*next_depth, depth + 1);
+            }
+        }
+        for layer in &mut layers {
+            layer.sort_by_key(|node| order.get(node));
+        }
+        Ok(layers)
+    }
+}
+
+/// For graphs that may contain cycles, which can still be ordered once each cycle is collapsed into a single node
+pub trait StronglyConnectedComponents: TopologicalSort {
+    /// Tarjan's algorithm. Components are in topological order i.e. any edge between two components goes
+    /// from an earlier one to a later one, and nodes within a component are in the order they were found.
+    fn components(&self) -> Vec<Vec<&Self::Node>> {
+        let mut tarjan = Tarjan { indices: HashMap::new(), low_links: HashMap::new(), stack: Vec::new(), on_stack: HashSet::new() };
+        let mut components = Vec::new();
+
+        for root in self.get_all_nodes() {
+            if tarjan.indices.contains_key(root) { continue }
+
+            // (node, its edges, index of the next edge to follow), in place of recursion
+            let mut call_stack = vec![(root, self.get_edges(root), 0)];
+            tarjan.visit(root);
+
+            while let Some((node, edges, next_edge)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(next) = edges.get(*next_edge).copied() {
+                    *next_edge += 1;
+                    if !tarjan.indices.contains_key(next) {
+                        tarjan.visit(next);
+                        call_stack.push((next, self.get_edges(next), 0));
+                    } else if tarjan.on_stack.contains(next) {
+                        tarjan.lower(node, tarjan.indices[next]);
+                    }
+                    continue
+                }
+
+                call_stack.pop();
+                if let Some((parent, _, _)) = call_stack.last() {
+                    tarjan.lower(parent, tarjan.low_links[node]);
+                }
+                if tarjan.low_links[node] == tarjan.indices[node] {
+                    let start = tarjan.stack.iter().rposition(|n| *n == node).unwrap();
+                    let component = tarjan.stack.split_off(start);
+                    for n in &component { tarjan.on_stack.remove(n); }
+                    components.push(component);
+                }
+            }
+        }
+        // Tarjan's algorithm completes a component only after every component reachable from it
+        components.reverse();
+        components
+    }
+
+    /// Collapses each component into a single node, leaving a DAG
+    fn condensation(&self) -> Condensation<&Self::Node> {
+        let components = self.components();
+        let component_of: HashMap<&Self::Node, usize> = components.iter().enumerate()
+            .flat_map(|(i, component)| component.iter().map(move |node| (*node, i)))
+            .collect();
+        let edges = components.iter().enumerate().map(|(i, component)| {
+            let mut targets: Vec<usize> = component.iter()
+                .flat_map(|node| self.get_edges(node))
+                .map(|next| component_of[next])
+                .filter(|j| *j != i)
+                .collect();
+            targets.sort();
+            targets.dedup();
+            targets
+        }).collect();
+        let cyclic = components.iter()
+            .map(|component| component.len() > 1 || self.get_edges(component[0]).contains(&component[0]))
+            .collect();
+        let ids = (0..components.len()).collect();
+        Condensation { components, component_of, edges, cyclic, ids }
+    }
+}
+
+pub struct Condensation<T> {
+    /// In topological order, see StronglyConnectedComponents::components
+    pub components: Vec<Vec<T>>,
+    /// Index into components
+    pub component_of: HashMap<T, usize>,
+    /// Indices of the components each component has an edge to, excluding itself
+    pub edges: Vec<Vec<usize>>,
+    cyclic: Vec<bool>,
+    // for TopologicalSort to hand out references to
+    ids: Vec<usize>,
+}
+
+impl<T> Condensation<T> {
+    /// Whether the component contains a cycle, which may be a single node with an edge to itself
+    pub fn is_cyclic(&self, component: usize) -> bool {
+        self.cyclic[component]
+    }
+}
+
+/// Components are already in topological order, but this allows e.g. grouping them into layers
+impl<T> TopologicalSort for Condensation<T> {
+    type Node = usize;
+
+    fn get_all_nodes(&self) -> Vec<&usize> {
+        self.ids.iter().collect()
+    }
+
+    fn get_edges(&self, component: &usize) -> Vec<&usize> {
+        self.edges[*component].iter().collect()
+    }
+}
+
+// Not public, internal state tracking for strongly connected components
+struct Tarjan<'a, T> {
+    indices: HashMap<&'a T, usize>,
+    low_links: HashMap<&'a T, usize>,
+    stack: Vec<&'a T>,
+    on_stack: HashSet<&'a T>,
+}
+
+impl<'a, T: Hash + Eq> Tarjan<'a, T> {
+    fn visit(&mut self, node: &'a T) {
+        let index = self.indices.len();
+        self.indices.insert(node, index);
+        self.low_links.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+    }
+
+    fn lower(&mut self, node: &'a T, low_link: usize) {
+        let existing = self.low_links.get_mut(node).unwrap();
+        *existing = min(*existing, low_link);
+    }
+}
+
+/// Kept sorted largest first, so the smallest can be popped from the end
+fn insert_sorted<'a, T, F: FnMut(&T, &T) -> Ordering>(sorted: &mut Vec<&'a T>, value: &'a T, cmp: &mut F) {
+    let i = sorted.partition_point(|other| cmp(other, value) == Ordering::Greater);
+    sorted.insert(i, value);
+}
+
+/// A cycle among the given nodes, if there is one. Nodes without an incoming edge from the others are
+/// removed until every remaining node has one (as Kahn's algorithm would), so walking incoming edges
+/// backwards from any of them must eventually repeat a node.
+fn find_cycle<'a, G: TopologicalSort + ?Sized>(graph: &'a G, mut nodes: HashSet<&'a G::Node>) -> Option<GraphContainsCycleError<&'a G::Node>> {
+    let mut in_degrees: HashMap<&G::Node, usize> = nodes.iter().map(|node| (*node, 0)).collect();
+    for node in &nodes {
+        for next in graph.get_edges(node) {
+            if let Some(in_degree) = in_degrees.get_mut(next) { *in_degree += 1 }
+        }
+    }
+    let mut sources: Vec<_> = in_degrees.iter().filter(|(_, in_degree)| **in_degree == 0).map(|(node, _)| *node).collect();
+    while let Some(node) = sources.pop() {
+        nodes.remove(node);
+        for next in graph.get_edges(node) {
+            if let Some(in_degree) = in_degrees.get_mut(next) {
+                *in_degree -= 1;
+                if *in_degree == 0 { sources.push(next) }
+            }
+        }
+    }
+
+    let mut predecessors: HashMap<&G::Node, &G::Node> = HashMap::new();
+    for node in &nodes {
+        for next in graph.get_edges(node) {
+            if nodes.contains(next) { predecessors.insert(next, node); }
+        }
+    }
+
+    let mut walked = vec![*nodes.iter().next()?];
+    let mut seen: HashSet<&G::Node> = walked.iter().copied().collect();
+    loop {
+        let previous = predecessors[walked.last().unwrap()];
+        if !seen.insert(previous) {
+            // walked backwards, so reverse to follow the edges
+            let start = walked.iter().position(|node| *node == previous).unwrap();
+            let mut cycle = walked.split_off(start);
+            cycle.reverse();
+            let (from, to) = (*cycle.last().unwrap(), cycle[0]);
+            return Some(GraphContainsCycleError { from, to, cycle })
+        }
+        walked.push(previous);
+    }
 }
 
 // Not public, internal state tracking for topological sort
 enum TopologicalSortState { ToVisit, Visiting, Visited }
 
 
-/// Shoelace formula and Pick's theorem:
-/// Shoelace formula (https://en.wikipedia.org/wiki/Shoelace_formula) - For a shape defined by a sequence of corners 
-///   (positively oriented i.e. going counter clockwise), the area is the sum of 1/2 (x_i - x_{i+1})(y_i + y_{i+1}).
-///   This can be demonstrated by adding/subtracting trapezoids, with the other two points on the x-axis, as the area
-///   of the trapezoid [(x1, 0), (x1, y1), (x2, y2), (x2, 0)] is (x2 - x1)(0.5(y1 + y2)) i.e. base * average height.
-///   It can also be viewed as many triangles, with the third point at the origin. Either way, it can be simplified to
-///   0.5 * Sum { x_i ( y_{i+1} - y_{i-1} ) }   (wrapping around where necessary)
-/// Pick's theorem (https://en.wikipedia.org/wiki/Pick%27s_theorem) - The area of a "simple" polygon with integer coordinate vertices is
-///   A = i + b/2 - 1, where A=area, i=number of integer coordinates inside the shape, b=integer points along bounary (i.e. edges/corners).
-/// 
-/// Combining the two:
-///   Both problems are described in terms of points that make up the boundary of a shape. If instead we're thinking of each point as a "cell",
-///   then the shape [(0,0), (0,1), (1,1), (1,0)] has an area of 4 rather than 1. To solve this discrepancy, think of each point as being the
-///   center of that cell:
-/// +------+------+ <-- corner of "cell", unrelated to interior/boundary points. We want the area of this outer shape = number of cells = 4
-/// |      |      |
-/// |  *---|---*  | <-- boundary "point" (1,1) in center of cell, Shoelace formula/Pick's theorem give the area of this inner shape = 1
-/// |  |   |   |  |
-/// +------+------+
-/// |  |   |   |  |
-/// |  *---|---*  |
-/// |      |      |
-/// +------+------+
-///   The Shoelace formula and Pick's theorem describe the same area A = the inner area of the shape outlined by the center points,
-///   with the Shoelace formula being the easier way of calculating that. The number of cells is really just the number of "interior"
-///   and "boundary" points as describe in Pick's theorem i.e. counting the number of cell centers, with all 4 points in the 2x2 example
-///   above being boundary points, and the actual borders of the "cells" having nothing to do with interior/boundary points.
-/// We want Number of Cells = i + b
-/// A = Area of the shape bounded by the cell centers, gotten from the Shoelace formula.
-/// b = Boundary points = number of cells on the perimeter, easy to count from the description of the shape's border cells.
-/// Pick's theorem: A = i + b/2 - 1      =>      i = A - b/2 + 1
-/// Therefore: Number of Cells = i + b = (A - b/2 + 1) + b = A + b/2 + 1
-/// Hence to go from Shoelace formula result to number of cells, just add (cells on perimeter / 2 + 1)
-fn shoelace_area_from_boundary_points(points: &Vec<Point>) -> i64 {
-    let len = points.len();
-    let total: i64 = (0..len).map(|i| {
-        let p = points[i];
-        let previous = points[(i + len - 1) % len];
-        let next = points[(i + 1) % len];
-        (p.x as i64) * ((next.y - previous.y) as i64)
-    }).sum();
-    let total = total / 2;
-    // rather than enforcing the correct orientation, just flip sign if end result is negative
-    if total < 0 { -total } else { total }
-}
-
-pub fn shoelace_area_enclosed_cells_including_border(perimeter_cells: &Vec<Point>) -> i64 {
-    let len = perimeter_cells.len();
-    let perimeter_len: u64 = (0..len).map(|i| {
-        let p1 = &perimeter_cells[i];
-        let p2 = &perimeter_cells[(i+1) % len];
-        if !p1.is_orthogonal_to(p2) { panic!("Invalid cell outline, not grid aligned!") }
-        p1.orthogonal_distance(p2)
-    }).sum();
-    let perimeter_len = perimeter_len as i64;
-    shoelace_area_from_boundary_points(perimeter_cells) + (perimeter_len/2) + 1
+/// Number of cells enclosed by the outline through their centers, including the outline itself.
+/// See polygon::Polygon::enclosed_cells, which this is kept as a shorthand for.
+pub fn shoelace_area_enclosed_cells_including_border(perimeter_cells: &[Point]) -> i64 {
+    polygon::Polygon::new(perimeter_cells.to_vec()).enclosed_cells()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixed costs between lettered states, remembering the best cost to each
+    struct Edges {
+        edges: Vec<(char, usize, char)>,
+        best: HashMap<char, usize>,
+    }
+
+    impl Edges {
+        fn new(edges: &[(char, usize, char)]) -> Self {
+            Edges { edges: edges.to_vec(), best: HashMap::new() }
+        }
+    }
+
+    impl Dijkstra for Edges {
+        type State = char;
+
+        fn is_end(&self, state: &char) -> bool {
+            *state == 'E'
+        }
+
+        fn neighbours(&self, state: &char) -> Vec<(usize, char)> {
+            self.edges.iter().filter(|(from, _, _)| from == state).map(|&(_, cost, to)| (cost, to)).collect()
+        }
+
+        fn try_improve(&mut self, state: &char, cost: usize) -> bool {
+            if self.best.get(state).is_some_and(|best| *best <= cost) { return false }
+            self.best.insert(*state, cost);
+            true
+        }
+    }
+
+    fn all_paths(edges: &[(char, usize, char)]) -> Vec<String> {
+        let mut paths: Vec<String> = Edges::new(edges).search_all_paths(vec!['S']).into_iter()
+            .map(|path| path.states.into_iter().collect())
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn all_paths_through_ties() {
+        assert_eq!(all_paths(&[('S', 1, 'A'), ('S', 1, 'B'), ('A', 1, 'E'), ('B', 1, 'E'), ('S', 3, 'E')]), ["SAE", "SBE"]);
+    }
+
+    #[test]
+    fn all_paths_with_zero_cost_cycles() {
+        let edges = [('S', 1, 'A'), ('A', 0, 'S'), ('A', 0, 'B'), ('B', 0, 'A'), ('B', 1, 'E'), ('S', 1, 'B')];
+        assert_eq!(all_paths(&edges), ["SABE", "SBE"]);
+    }
+
+    #[test]
+    fn starts_are_not_revisited() {
+        let stats = Edges::new(&[('S', 0, 'S'), ('S', 1, 'E')]).search_with_stats(vec!['S']);
+        assert_eq!(stats.cost, Some(1));
+        assert_eq!(stats.expanded, 1);
+    }
+
+    /// Directed graph from a list of edges, with nodes in order of first appearance
+    struct EdgeList {
+        nodes: Vec<char>,
+        edges: Vec<(char, char)>,
+    }
+
+    impl EdgeList {
+        fn new(edges: &[(char, char)]) -> Self {
+            let mut nodes = Vec::new();
+            for &(from, to) in edges {
+                for node in [from, to] {
+                    if !nodes.contains(&node) { nodes.push(node) }
+                }
+            }
+            EdgeList { nodes, edges: edges.to_vec() }
+        }
+    }
+
+    impl TopologicalSort for EdgeList {
+        type Node = char;
+
+        fn get_all_nodes(&self) -> Vec<&char> {
+            self.nodes.iter().collect()
+        }
+
+        fn get_edges(&self, node: &char) -> Vec<&char> {
+            self.edges.iter().filter(|(from, _)| from == node).map(|(_, to)| to).collect()
+        }
+    }
+
+    impl StronglyConnectedComponents for EdgeList {}
+
+    fn assert_closed_path(graph: &EdgeList, error: &GraphContainsCycleError<&char>) {
+        assert_eq!((error.cycle[0], *error.cycle.last().unwrap()), (error.to, error.from));
+        let mut path = error.cycle.clone();
+        path.push(error.to);
+        for edge in path.windows(2) {
+            assert!(graph.get_edges(edge[0]).contains(&edge[1]), "No edge {} -> {}", edge[0], edge[1]);
+        }
+    }
+
+    #[test]
+    fn sort_reports_cycle_as_closed_path() {
+        let edges = [('a', 'b'), ('b', 'c'), ('c', 'd'), ('d', 'b'), ('a', 'e')];
+        // sort borrows its graph mutably for as long as the error lives, so check against a copy
+        let mut graph = EdgeList::new(&edges);
+        let error = graph.sort().unwrap_err();
+        let mut cycle: Vec<char> = error.cycle.iter().copied().copied().collect();
+        cycle.sort();
+        assert_eq!(cycle, ['b', 'c', 'd']);
+        assert_closed_path(&EdgeList::new(&edges), &error);
+    }
+
+    #[test]
+    fn sort_kahn_breaks_ties_in_order() {
+        let graph = EdgeList::new(&[('c', 'a'), ('b', 'a'), ('d', 'e')]);
+        let sorted: String = graph.sort_kahn(|a, b| a.cmp(b)).unwrap().into_iter().collect();
+        assert_eq!(sorted, "bcade");
+        let sorted: String = graph.sort_kahn(|a, b| b.cmp(a)).unwrap().into_iter().collect();
+        assert_eq!(sorted, "decba");
+
+        let cyclic = EdgeList::new(&[('a', 'b'), ('b', 'c'), ('c', 'b')]);
+        assert_closed_path(&cyclic, &cyclic.sort_kahn(|a, b| a.cmp(b)).unwrap_err());
+    }
+
+    #[test]
+    fn layers_group_independent_nodes() {
+        let graph = EdgeList::new(&[('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd'), ('x', 'y')]);
+        let layers: Vec<String> = graph.layers().unwrap().into_iter().map(|layer| layer.into_iter().collect()).collect();
+        assert_eq!(layers, ["ax", "bcy", "d"]);
+    }
+
+    #[test]
+    fn find_cycle_only_in_cyclic_graphs() {
+        let dag = EdgeList::new(&[('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd')]);
+        assert!(find_cycle(&dag, dag.get_all_nodes().into_iter().collect()).is_none());
+
+        // Walking backwards from 'e' or 'a' alone wouldn't find the cycle
+        let graph = EdgeList::new(&[('a', 'b'), ('b', 'c'), ('c', 'b'), ('c', 'e')]);
+        let error = find_cycle(&graph, graph.get_all_nodes().into_iter().collect()).unwrap();
+        assert_closed_path(&graph, &error);
+        assert_eq!(error.cycle.len(), 2);
+    }
+
+    #[test]
+    fn components_in_topological_order() {
+        let graph = EdgeList::new(&[('a', 'b'), ('b', 'c'), ('c', 'b'), ('c', 'd'), ('d', 'd'), ('a', 'e')]);
+        let mut components: Vec<Vec<char>> = graph.components().into_iter()
+            .map(|component| component.into_iter().copied().collect())
+            .collect();
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(components[0], ['a']);
+        let position = |node| components.iter().position(|component| component.contains(&node)).unwrap();
+        assert!(position('b') < position('d'));
+        assert!(position('a') < position('e'));
+
+        let condensation = graph.condensation();
+        let cyclic: Vec<bool> = ['a', 'b', 'd', 'e'].map(|node| condensation.is_cyclic(condensation.component_of[&node])).into();
+        assert_eq!(cyclic, [false, true, true, false]);
+        // the self-loop doesn't survive into the condensed DAG
+        let d = condensation.component_of[&'d'];
+        assert!(!condensation.edges[d].contains(&d));
+        let layers: Vec<Vec<String>> = condensation.layers().unwrap().into_iter()
+            .map(|layer| {
+                let mut layer: Vec<String> = layer.into_iter().map(|component| components[*component].iter().collect()).collect();
+                layer.sort();
+                layer
+            })
+            .collect();
+        assert_eq!(layers, [vec!["a"], vec!["bc", "e"], vec!["d"]]);
+    }
+
+    /// Breadth first search over an EdgeList, borrowed so the nodes it marked can be checked afterwards
+    struct Walk {
+        graph: EdgeList,
+        marked: Vec<char>,
+    }
+
+    impl Walk {
+        fn new() -> Self {
+            let graph = EdgeList::new(&[('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd'), ('d', 'e'), ('c', 'f'), ('x', 'y')]);
+            Walk { graph, marked: Vec::new() }
+        }
+    }
+
+    impl BreadthFirstSearch for &mut Walk {
+        type Node = char;
+
+        fn mark(&mut self, node: &char) -> bool {
+            if self.marked.contains(node) { return false }
+            self.marked.push(*node);
+            true
+        }
+
+        fn neighbours(&self, node: &char) -> Vec<char> {
+            self.graph.get_edges(node).into_iter().copied().collect()
+        }
+    }
+
+    #[test]
+    fn bfs_tree_paths() {
+        let tree = (&mut Walk::new()).search_tree('a');
+        assert_eq!(tree.distances[&'e'], 3);
+        assert_eq!(tree.path_to(&'e'), Some(vec!['a', 'b', 'd', 'e']));
+        assert_eq!(tree.path_to(&'f'), Some(vec!['a', 'c', 'f']));
+        assert_eq!(tree.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(tree.path_to(&'x'), None);
+    }
+
+    #[test]
+    fn bfs_stops_at_goal() {
+        let mut walk = Walk::new();
+        assert_eq!((&mut walk).search_until('a', |node| *node == 'd'), Some(vec!['a', 'b', 'd']));
+        // 'd' was found from 'b' before 'c' was expanded
+        assert_eq!(walk.marked, ['a', 'b', 'c', 'd']);
+        assert_eq!((&mut Walk::new()).search_until('a', |node| *node == 'y'), None);
+    }
+
+    #[test]
+    fn bfs_layers() {
+        let sizes: Vec<usize> = (&mut Walk::new()).layers('a').map(|layer| layer.len()).collect();
+        assert_eq!(sizes, [1, 2, 2, 1]);
+        let mut walk = Walk::new();
+        assert_eq!((&mut walk).layers('a').nth(1), Some(vec!['b', 'c']));
+        // only as far as was iterated
+        assert!(!walk.marked.contains(&'e'));
+    }
 }
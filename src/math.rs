@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use num::{CheckedMul, Integer};
+
+/// Greatest common divisor of all the values, 0 if there are none
+pub fn gcd_all<T: Integer + Copy>(values: impl IntoIterator<Item=T>) -> T {
+    values.into_iter().fold(T::zero(), |a, b| a.gcd(&b))
+}
+
+/// Least common multiple of all the values, 1 if there are none, or None if it overflows
+pub fn lcm_all<T: Integer + CheckedMul + Copy>(values: impl IntoIterator<Item=T>) -> Option<T> {
+    values.into_iter().try_fold(T::one(), |a, b| checked_lcm(a, b))
+}
+
+/// lcm(a, b), or None if it overflows
+pub fn checked_lcm<T: Integer + CheckedMul + Copy>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() { return Some(T::zero()) }
+    (a / a.gcd(&b)).checked_mul(&b)
+}
+
+/// (g, x, y) such that a*x + b*y = g = gcd(a, b)
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    // keep the gcd positive, whatever the signs of a and b
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+/// x such that a*x ≡ 1 (mod modulus), with 0 <= x < modulus, or None if a and modulus aren't coprime,
+/// or modulus isn't positive
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 { return None }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// base^exp % modulus, by repeated squaring. Panics if modulus is 0.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "Modulus must be positive");
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 { result = result * base % modulus }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// n ≡ residue (mod modulus)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Congruence {
+    pub residue: i128,
+    pub modulus: i128,
+}
+
+impl Congruence {
+    /// Residue is normalised to 0 <= residue < modulus, which must be positive
+    pub fn new(residue: i128, modulus: i128) -> Self {
+        assert!(modulus > 0, "Modulus must be positive");
+        Congruence { residue: residue.rem_euclid(modulus), modulus }
+    }
+}
+
+#[derive(Debug)]
+pub enum CrtError {
+    /// No number satisfies both the congruences before this one (combined) and this one
+    Incompatible { combined: Congruence, congruence: Congruence },
+    /// The lcm of the moduli doesn't fit in an i128
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::Incompatible { combined, congruence } => write!(f,
+                "n ≡ {} (mod {}) is incompatible with n ≡ {} (mod {})",
+                congruence.residue, congruence.modulus, combined.residue, combined.modulus),
+            CrtError::Overflow => write!(f, "Combined modulus overflows"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Generalised Chinese remainder theorem: the single congruence satisfied by exactly the numbers that
+/// satisfy all of the given ones, whose moduli don't have to be coprime. Its modulus is the lcm of theirs.
+pub fn crt(congruences: impl IntoIterator<Item=Congruence>) -> Result<Congruence, CrtError> {
+    congruences.into_iter().try_fold(Congruence::new(0, 1), |combined, congruence| {
+        let Congruence { residue: a, modulus: m } = combined;
+        let Congruence { residue: b, modulus: k } = congruence;
+        // m*x + k*y = g, so n = a + m*x*(b - a)/g satisfies both when g divides b - a
+        let (g, x, _) = extended_gcd(m, k);
+        if (b - a) % g != 0 { return Err(CrtError::Incompatible { combined, congruence }) }
+        let lcm = (m / g).checked_mul(k).ok_or(CrtError::Overflow)?;
+        // below k / g so that m * steps < lcm, which doesn't overflow
+        let steps = mul_mod((b - a) / g, x, k / g);
+        Ok(Congruence::new(a + m * steps, lcm))
+    })
+}
+
+/// a * b % modulus, between 0 and modulus, even if a * b would overflow
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) { return product.rem_euclid(modulus) }
+    // double and add, where both terms are below modulus < 2^127, so their sum fits in a u128
+    let modulus = modulus as u128;
+    let (mut a, mut b) = (a.rem_euclid(modulus as i128) as u128, b.rem_euclid(modulus as i128) as u128);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 { result = (result + a) % modulus }
+        a = (a + a) % modulus;
+        b >>= 1;
+    }
+    result as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(gcd_all::<u32>([]), 0);
+        assert_eq!(lcm_all([4, 6, 10]), Some(60));
+        assert_eq!(lcm_all::<u32>([]), Some(1));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+    }
+
+    #[test]
+    fn extended_gcd_coefficients() {
+        for (a, b, gcd) in [(240, 46, 2), (-240, 46, 2), (7, 0, 7), (0, -7, 7)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn modular_inverse_and_power() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 10), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_pow(3, 200, 13), 9);
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be positive")]
+    fn power_modulo_zero() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn crt_coprime() {
+        let n = crt([Congruence::new(2, 3), Congruence::new(3, 5), Congruence::new(2, 7)]).unwrap();
+        assert_eq!(n, Congruence::new(23, 105));
+    }
+
+    #[test]
+    fn crt_not_coprime() {
+        // 3 mod 4 and 1 mod 6 agree mod 2, leaving 7 mod 12
+        let n = crt([Congruence::new(3, 4), Congruence::new(1, 6)]).unwrap();
+        assert_eq!(n, Congruence::new(7, 12));
+    }
+
+    #[test]
+    fn crt_incompatible() {
+        // 1 mod 4 is odd, 2 mod 6 is even
+        let Err(CrtError::Incompatible { combined, congruence }) = crt([Congruence::new(1, 4), Congruence::new(2, 6)]) else {
+            panic!("Expected incompatible congruences")
+        };
+        assert_eq!(combined, Congruence::new(1, 4));
+        assert_eq!(congruence, Congruence::new(2, 6));
+    }
+
+    #[test]
+    fn crt_with_large_moduli() {
+        let (a, b) = (i128::MAX / 3, i128::MAX / 3 - 1);
+        let error = crt([Congruence::new(1, a), Congruence::new(2, b)]).unwrap_err();
+        assert!(matches!(error, CrtError::Overflow));
+        // products of the moduli overflow, but their lcm doesn't
+        let (a, b) = (1_i128 << 100, 3_i128 << 90);
+        let n = crt([Congruence::new(5, a), Congruence::new(5 + (1 << 90), b)]).unwrap();
+        assert_eq!(n.modulus, 3 << 100);
+        assert_eq!(n.residue % a, 5);
+        assert_eq!(n.residue % b, 5 + (1 << 90));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{cmp::max, collections::BTreeSet};
+
+use crate::{cycle::Cycle, math::{self, Congruence}};
+
+/// A set of natural numbers that is eventually periodic: some members below start, then from start onwards,
+/// n is a member exactly when (n - start) % period is one of the offsets.
+/// e.g. the steps at which a walk around a graph is at a particular node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeriodicSet {
+    /// Members below start
+    initial: BTreeSet<usize>,
+    start: usize,
+    period: usize,
+    /// Each less than period
+    offsets: BTreeSet<usize>,
+}
+
+impl PeriodicSet {
+    /// Initial values at or above start are ignored, as are any repeats of offsets modulo the period
+    pub fn new(initial: impl IntoIterator<Item=usize>, start: usize, period: usize, offsets: impl IntoIterator<Item=usize>) -> Self {
+        assert!(period > 0, "Period must be positive");
+        PeriodicSet {
+            initial: initial.into_iter().filter(|n| *n < start).collect(),
+            start,
+            period,
+            offsets: offsets.into_iter().map(|offset| offset % period).collect(),
+        }
+    }
+
+    /// The steps at which the cycle's states satisfy is_member
+    pub fn from_cycle<S, F: FnMut(&S) -> bool>(cycle: &Cycle<S>, mut is_member: F) -> Self {
+        let states = cycle.states();
+        let initial: Vec<usize> = (0..cycle.prefix).filter(|i| is_member(&states[*i])).collect();
+        let offsets: Vec<usize> = (0..cycle.period).filter(|i| is_member(&states[cycle.prefix + i])).collect();
+        Self::new(initial, cycle.prefix, cycle.period, offsets)
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// False if there are only finitely many members
+    pub fn is_infinite(&self) -> bool {
+        !self.offsets.is_empty()
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        if n < self.start {
+            self.initial.contains(&n)
+        } else {
+            self.offsets.contains(&((n - self.start) % self.period))
+        }
+    }
+
+    pub fn first(&self) -> Option<usize> {
+        self.nth(0)
+    }
+
+    /// The nth smallest member, counting from 0. None if there are fewer members, or it's too large for a usize.
+    pub fn nth(&self, n: usize) -> Option<usize> {
+        if n < self.initial.len() { return self.initial.iter().nth(n).copied() }
+        if self.offsets.is_empty() { return None }
+        let n = n - self.initial.len();
+        let offset = self.offsets.iter().nth(n % self.offsets.len())?;
+        self.repeated(n / self.offsets.len(), *offset)
+    }
+
+    /// Members less than limit, in increasing order
+    pub fn below(&self, limit: usize) -> impl Iterator<Item=usize> + '_ {
+        let repeats = if self.offsets.is_empty() { 0 } else { usize::MAX };
+        self.initial.iter().map(|n| Some(*n))
+            .chain((0..repeats).flat_map(move |repeat| self.offsets.iter().map(move |offset| self.repeated(repeat, *offset))))
+            // members too large for a usize are past any limit too
+            .map_while(|n| n)
+            .take_while(move |n| *n < limit)
+    }
+
+    /// start + repeat * period + offset, or None if it overflows
+    fn repeated(&self, repeat: usize, offset: usize) -> Option<usize> {
+        repeat.checked_mul(self.period)?.checked_add(self.start)?.checked_add(offset)
+    }
+
+    /// Numbers in both sets. Rather than stepping through the combined period, each pair of offsets is
+    /// solved as a pair of congruences with the Chinese remainder theorem, which works even when the
+    /// moduli aren't coprime, so this is O(offsets²) however large the periods are.
+    /// None if the combined period, the lcm of both periods, overflows.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let start = max(self.start, other.start);
+        let initial: Vec<usize> = self.below(start).filter(|n| other.contains(*n)).collect();
+
+        let period = math::checked_lcm(self.period, other.period)?;
+        // the combined modulus is the period, so an error can only mean the offsets never coincide
+        let offsets: Vec<usize> = self.offsets.iter()
+            .flat_map(|a| other.offsets.iter().map(move |b| [
+                Congruence::new((self.start + a) as i128, self.period as i128),
+                Congruence::new((other.start + b) as i128, other.period as i128),
+            ]))
+            .filter_map(|congruences| math::crt(congruences).ok())
+            .map(|n| (n.residue - start as i128).rem_euclid(period as i128) as usize)
+            .collect();
+        Some(Self::new(initial, start, period, offsets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members_before_and_after_start() {
+        // 1, then from 5 onwards 6, 8, 11, 13, 16, ...
+        let set = PeriodicSet::new([1, 7], 5, 5, [1, 3, 8]);
+        assert!(set.is_infinite());
+        assert_eq!(set.below(17).collect::<Vec<_>>(), [1, 6, 8, 11, 13, 16]);
+        assert!(set.contains(1) && set.contains(1001) && !set.contains(7));
+        assert_eq!(set.first(), Some(1));
+        assert_eq!(set.nth(5), Some(16));
+    }
+
+    #[test]
+    fn finite_sets() {
+        let set = PeriodicSet::new([2, 3], 4, 1, []);
+        assert!(!set.is_infinite());
+        assert_eq!(set.nth(1), Some(3));
+        assert_eq!(set.nth(2), None);
+        assert_eq!(set.below(usize::MAX).count(), 2);
+    }
+
+    #[test]
+    fn from_cycle() {
+        let cycle = Cycle::find(0, |n| if *n < 4 { n + 1 } else { 2 });
+        let set = PeriodicSet::from_cycle(&cycle, |n| n % 2 == 0);
+        assert_eq!((set.start(), set.period()), (2, 3));
+        assert_eq!(set.below(10).collect::<Vec<_>>(), [0, 2, 4, 5, 7, 8]);
+    }
+
+    #[test]
+    fn intersect_with_shared_factors() {
+        // 3 mod 4 and 1 mod 6 differ by a multiple of gcd(4, 6) = 2, so they meet once every 12, at 7 mod 12
+        let a = PeriodicSet::new([], 0, 4, [3]);
+        let b = PeriodicSet::new([], 0, 6, [1]);
+        let both = a.intersect(&b).unwrap();
+        assert_eq!(both.period(), 12);
+        assert_eq!(both.below(30).collect::<Vec<_>>(), [7, 19]);
+        assert!(both.below(1000).all(|n| a.contains(n) && b.contains(n)));
+    }
+
+    #[test]
+    fn intersect_with_different_starts() {
+        let a = PeriodicSet::new([2], 3, 2, [0]);
+        let b = PeriodicSet::new([0, 2, 4], 6, 3, [0]);
+        let both = a.intersect(&b).unwrap();
+        assert_eq!(both.below(30).collect::<Vec<_>>(), [2, 9, 15, 21, 27]);
+    }
+
+    #[test]
+    fn intersect_without_common_members() {
+        let evens = PeriodicSet::new([], 0, 2, [0]);
+        let odds = PeriodicSet::new([], 0, 2, [1]);
+        assert_eq!(evens.intersect(&odds).unwrap().first(), None);
+    }
+
+    #[test]
+    fn overflowing_members_are_left_out() {
+        let large = PeriodicSet::new([], 0, usize::MAX / 2, [1]);
+        assert_eq!(large.nth(2), Some(usize::MAX));
+        assert_eq!(large.nth(3), None);
+        assert_eq!(large.below(usize::MAX).count(), 2);
+        let coprime = PeriodicSet::new([], 0, usize::MAX / 2 - 1, [1]);
+        assert!(large.intersect(&coprime).is_none());
+    }
+}
@@ -1,4 +1,6 @@
-use std::{io::Error, ops::{Add, Neg}, str::FromStr};
+use std::{ops::{Add, Neg}, str::FromStr};
+
+use crate::error::{self, AocError};
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Debug)]
 pub struct Point3 { pub x: i64, pub y: i64, pub z: i64 }
@@ -30,12 +32,12 @@ impl Neg for Point3 {
 }
 
 impl FromStr for Point3 {
-    type Err = Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let axes: Vec<i64> = s.split(',').map(|s| s.trim().parse().unwrap()).collect();
-        assert!(axes.len() == 3);
-        Ok(Point3 { x: axes[0], y: axes[1], z: axes[2] })
+        let axes = s.split(',').map(|s| error::parse(s.trim())).collect::<Result<Vec<i64>, _>>()?;
+        let [x, y, z] = axes[..] else { return Err(AocError::invalid(s, "Expected 'x,y,z'")) };
+        Ok(Point3 { x, y, z })
     }
 }
 
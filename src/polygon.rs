@@ -0,0 +1,228 @@
+use num::integer::gcd;
+
+use crate::{direction::Direction, point::Point};
+
+/// As seen on screen, with y increasing downwards as in a Grid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    Clockwise,
+    Anticlockwise,
+    /// No area, e.g. all the vertices are in a line
+    Degenerate,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon with integer vertices, closed by an edge from the last vertex back to the first.
+/// Edges don't have to be axis-aligned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// If the last vertex repeats the first, to close the outline, it's only kept once
+    pub fn new(mut vertices: Vec<Point>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    /// The outline traced by following the moves from start, e.g. a dig plan.
+    /// The moves should end back at start, but the closing edge is added if not.
+    pub fn from_moves(start: Point, moves: impl IntoIterator<Item=(Direction, i64)>) -> Self {
+        let mut position = start;
+        let mut vertices = vec![position];
+        for (dir, steps) in moves {
+            position += Point::from(dir) * steps;
+            vertices.push(position);
+        }
+        Self::new(vertices)
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Each edge as (from, to), including the closing edge
+    pub fn edges(&self) -> impl Iterator<Item=(Point, Point)> + '_ {
+        let len = self.vertices.len();
+        (0..len).map(move |i| (self.vertices[i], self.vertices[(i + 1) % len]))
+    }
+
+    /// Twice the area, so that it's always an integer, positive if the vertices go clockwise (see Orientation)
+    pub fn doubled_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.doubled_signed_area().abs() as f64 / 2.0
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        match self.doubled_signed_area() {
+            0 => Orientation::Degenerate,
+            area if area > 0 => Orientation::Clockwise,
+            _ => Orientation::Anticlockwise,
+        }
+    }
+
+    /// Total length of the edges
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| (((b.x - a.x).pow(2) + (b.y - a.y).pow(2)) as f64).sqrt()).sum()
+    }
+
+    /// Number of integer points on the edges, including the vertices
+    pub fn boundary_points(&self) -> i64 {
+        // an edge passes through a point every (dx, dy) / gcd(dx, dy), counting its start but not its end
+        self.edges().map(|(a, b)| gcd(b.x - a.x, b.y - a.y)).sum()
+    }
+
+    /// Number of integer points strictly inside, from Pick's theorem: A = i + b/2 - 1
+    pub fn interior_points(&self) -> i64 {
+        (self.doubled_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Shoelace formula and Pick's theorem:
+    /// Shoelace formula (https://en.wikipedia.org/wiki/Shoelace_formula) - For a shape defined by a sequence of corners
+    ///   (positively oriented i.e. going counter clockwise), the area is the sum of 1/2 (x_i - x_{i+1})(y_i + y_{i+1}).
+    ///   This can be demonstrated by adding/subtracting trapezoids, with the other two points on the x-axis, as the area
+    ///   of the trapezoid [(x1, 0), (x1, y1), (x2, y2), (x2, 0)] is (x2 - x1)(0.5(y1 + y2)) i.e. base * average height.
+    ///   It can also be viewed as many triangles, with the third point at the origin. Either way, it can be simplified to
+    ///   0.5 * Sum { x_i ( y_{i+1} - y_{i-1} ) }   (wrapping around where necessary)
+    /// Pick's theorem (https://en.wikipedia.org/wiki/Pick%27s_theorem) - The area of a "simple" polygon with integer coordinate vertices is
+    ///   A = i + b/2 - 1, where A=area, i=number of integer coordinates inside the shape, b=integer points along bounary (i.e. edges/corners).
+    ///
+    /// Combining the two:
+    ///   Both problems are described in terms of points that make up the boundary of a shape. If instead we're thinking of each point as a "cell",
+    ///   then the shape [(0,0), (0,1), (1,1), (1,0)] has an area of 4 rather than 1. To solve this discrepancy, think of each point as being the
+    ///   center of that cell:
+    /// +------+------+ <-- corner of "cell", unrelated to interior/boundary points. We want the area of this outer shape = number of cells = 4
+    /// |      |      |
+    /// |  *---|---*  | <-- boundary "point" (1,1) in center of cell, Shoelace formula/Pick's theorem give the area of this inner shape = 1
+    /// |  |   |   |  |
+    /// +------+------+
+    /// |  |   |   |  |
+    /// |  *---|---*  |
+    /// |      |      |
+    /// +------+------+
+    ///   The Shoelace formula and Pick's theorem describe the same area A = the inner area of the shape outlined by the center points,
+    ///   with the Shoelace formula being the easier way of calculating that. The number of cells is really just the number of "interior"
+    ///   and "boundary" points as describe in Pick's theorem i.e. counting the number of cell centers, with all 4 points in the 2x2 example
+    ///   above being boundary points, and the actual borders of the "cells" having nothing to do with interior/boundary points.
+    /// We want Number of Cells = i + b
+    /// A = Area of the shape bounded by the cell centers, gotten from the Shoelace formula.
+    /// b = Boundary points = number of cells on the perimeter, easy to count from the description of the shape's border cells.
+    /// Pick's theorem: A = i + b/2 - 1      =>      i = A - b/2 + 1
+    /// Therefore: Number of Cells = i + b = (A - b/2 + 1) + b = A + b/2 + 1
+    /// Hence to go from Shoelace formula result to number of cells, just add (cells on perimeter / 2 + 1)
+    pub fn enclosed_cells(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Where the point is, counting the number of edges crossed by a ray from it to the right
+    pub fn locate(&self, p: &Point) -> Location {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            // how far p is to the left of the line from a to b, 0 if it's on the line
+            let cross = (b.x - a.x) * (p.y - a.y) - (p.x - a.x) * (b.y - a.y);
+            let within_edge = a.x.min(b.x) <= p.x && p.x <= a.x.max(b.x) && a.y.min(b.y) <= p.y && p.y <= a.y.max(b.y);
+            if cross == 0 && within_edge { return Location::Boundary }
+            // an edge counts if it has one end strictly below p and one at or above it, so vertices aren't counted twice
+            if (a.y > p.y) != (b.y > p.y) && (cross > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+        if inside { Location::Inside } else { Location::Outside }
+    }
+
+    /// Inside or on the boundary
+    pub fn contains(&self, p: &Point) -> bool {
+        self.locate(p) != Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Point { x, y }).collect())
+    }
+
+    #[test]
+    fn square_measurements() {
+        // right then down, so clockwise on screen
+        let square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2), (0, 0)]);
+        assert_eq!(square.vertices().len(), 4);
+        assert_eq!(square.orientation(), Orientation::Clockwise);
+        assert_eq!((square.area(), square.perimeter()), (4.0, 8.0));
+        assert_eq!((square.boundary_points(), square.interior_points(), square.enclosed_cells()), (8, 1, 9));
+        let reversed = polygon(&[(0, 2), (2, 2), (2, 0), (0, 0)]);
+        assert_eq!(reversed.orientation(), Orientation::Anticlockwise);
+        assert_eq!(polygon(&[(0, 0), (1, 1), (3, 3)]).orientation(), Orientation::Degenerate);
+    }
+
+    #[test]
+    fn dig_plan_outline() {
+        let moves = [(Direction::East, 3), (Direction::South, 2), (Direction::West, 3), (Direction::North, 2)];
+        let outline = Polygon::from_moves(Point { x: 0, y: 0 }, moves);
+        assert_eq!(outline, polygon(&[(0, 0), (3, 0), (3, 2), (0, 2)]));
+        assert_eq!(outline.enclosed_cells(), 12);
+    }
+
+    #[test]
+    fn diagonal_edges() {
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(triangle.area(), 8.0);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn locate_vertices_and_edges() {
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        for p in [(0, 0), (4, 0), (0, 4), (2, 0), (0, 3), (2, 2), (3, 1)] {
+            assert_eq!(triangle.locate(&Point { x: p.0, y: p.1 }), Location::Boundary, "{p:?}");
+        }
+        assert_eq!(triangle.locate(&Point { x: 1, y: 1 }), Location::Inside);
+        for p in [(3, 3), (-1, 0), (5, 0), (-1, 4), (2, -1)] {
+            assert_eq!(triangle.locate(&Point { x: p.0, y: p.1 }), Location::Outside, "{p:?}");
+        }
+    }
+
+    #[test]
+    fn locate_level_with_vertices() {
+        // concave, with a notch in the top whose vertices are level with points either side of it
+        let notched = polygon(&[(0, 0), (2, 0), (3, 2), (4, 0), (6, 0), (6, 4), (0, 4)]);
+        assert_eq!(notched.locate(&Point { x: 1, y: 2 }), Location::Inside);
+        assert_eq!(notched.locate(&Point { x: 3, y: 1 }), Location::Outside);
+        assert_eq!(notched.locate(&Point { x: 3, y: 2 }), Location::Boundary);
+        assert_eq!(notched.locate(&Point { x: -1, y: 0 }), Location::Outside);
+        assert_eq!(notched.locate(&Point { x: 3, y: 0 }), Location::Outside);
+        assert!(notched.contains(&Point { x: 5, y: 4 }));
+    }
+
+    #[test]
+    fn locate_agrees_with_picks_theorem() {
+        let shapes = [
+            polygon(&[(0, 0), (2, 0), (3, 2), (4, 0), (6, 0), (6, 4), (0, 4)]),
+            polygon(&[(0, 0), (8, 2), (5, 5), (7, 9), (1, 6), (3, 3)]),
+            polygon(&[(2, 0), (4, 4), (2, 8), (0, 4)]),
+        ];
+        for shape in shapes {
+            let locations: Vec<Location> = (-1..11).flat_map(|y| (-1..11).map(move |x| Point { x, y }))
+                .map(|p| shape.locate(&p))
+                .collect();
+            let count = |location| locations.iter().filter(|l| **l == location).count() as i64;
+            assert_eq!(count(Location::Boundary), shape.boundary_points(), "{shape:?}");
+            assert_eq!(count(Location::Inside), shape.interior_points(), "{shape:?}");
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::{fmt::{Debug, Display}, ops::Range};
+
+use num::PrimInt;
+
+use crate::interval::IntervalSet;
+
+/// A piecewise translation of integers: values in each source range are moved to the same position
+/// relative to its destination start, and values outside all of them map to themselves.
+/// e.g. the seed to soil map in 2023 day 5. T::max_value() can't be in a half-open source range,
+/// so it always maps to itself, and the pieces and gaps between them only need to cover the rest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// Sorted by source, which don't overlap
+    pieces: Vec<Piece<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Piece<T> {
+    source: Range<T>,
+    dest_start: T,
+}
+
+impl<T: PrimInt> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> RangeMap<T> {
+    /// Maps every value to itself
+    pub fn new() -> Self {
+        RangeMap { pieces: Vec::new() }
+    }
+
+    /// Maps source to the range of the same length starting at dest_start.
+    /// Panics if source overlaps a range that's already mapped, or the destination range doesn't fit in T.
+    pub fn insert(&mut self, source: Range<T>, dest_start: T) where T: Debug {
+        if let Err(e) = self.try_insert(source, dest_start) { panic!("{e}") }
+    }
+
+    /// As insert, leaving the map unchanged if source overlaps a range that's already mapped,
+    /// or the destination range doesn't fit in T
+    pub fn try_insert(&mut self, source: Range<T>, dest_start: T) -> Result<(), InsertError<T>> {
+        if source.is_empty() { return Ok(()) }
+        if dest_start.checked_add(&(source.end - source.start)).is_none() {
+            return Err(InsertError::DestinationOverflow { source, dest_start })
+        }
+        let i = self.pieces.partition_point(|piece| piece.source.end <= source.start);
+        if let Some(next) = self.pieces.get(i).filter(|next| next.source.start < source.end) {
+            return Err(InsertError::Overlap { source, existing: next.source.clone() })
+        }
+        self.pieces.insert(i, Piece { source, dest_start });
+        Ok(())
+    }
+
+    /// Source ranges, and the start of the range each maps to, in order of source
+    pub fn pieces(&self) -> impl Iterator<Item=(Range<T>, T)> + '_ {
+        self.pieces.iter().map(|piece| (piece.source.clone(), piece.dest_start))
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let i = self.pieces.partition_point(|piece| piece.source.end <= value);
+        match self.pieces.get(i) {
+            Some(piece) if piece.source.start <= value => piece.dest_start + (value - piece.source.start),
+            _ => value,
+        }
+    }
+
+    /// The map that applies self, then next, as a single lookup
+    pub fn then(&self, next: &Self) -> Self {
+        let pieces = self.segments()
+            .flat_map(|(source, dest_start)| {
+                let image = dest_start..dest_start + (source.end - source.start);
+                // shift the pieces of the image back to where they came from
+                next.split(image).into_iter().map(move |(part, part_dest)| Piece {
+                    source: part.start - dest_start + source.start..part.end - dest_start + source.start,
+                    dest_start: part_dest,
+                })
+            })
+            .collect();
+        Self::from_pieces(pieces)
+    }
+
+    /// The map back from destinations to sources, or None if some values of T would have more than one source or none.
+    /// e.g. day 5's almanac from locations back to seeds.
+    pub fn invert(&self) -> Option<Self> {
+        let mut images: Vec<Piece<T>> = self.segments()
+            .map(|(source, dest_start)| Piece { source: dest_start..dest_start + (source.end - source.start), dest_start: source.start })
+            .collect();
+        images.sort_by_key(|piece| piece.source.start);
+        // a bijection exactly when the images tile the whole range with no gaps or overlaps
+        let mut expected_start = T::min_value();
+        for image in &images {
+            if image.source.start != expected_start { return None }
+            expected_start = image.source.end;
+        }
+        (expected_start == T::max_value()).then(|| Self::from_pieces(images))
+    }
+
+    /// Every value of the set mapped, splitting its ranges where the pieces start and end
+    pub fn map_intervals(&self, values: &IntervalSet<T>) -> IntervalSet<T> {
+        values.ranges().iter()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(part, dest_start)| dest_start..dest_start + (part.end - part.start))
+            .collect()
+    }
+
+    /// Splits range into parts that are each within a single piece or gap, with the start each maps to
+    fn split(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut parts = Vec::new();
+        let mut start = range.start;
+        while start < range.end {
+            let (segment, dest_start) = self.segment_at(start);
+            let end = segment.end.min(range.end);
+            parts.push((start..end, dest_start + (start - segment.start)));
+            start = end;
+        }
+        parts
+    }
+
+    /// The piece containing value, or the gap between pieces as a piece mapping to itself
+    fn segment_at(&self, value: T) -> (Range<T>, T) {
+        let i = self.pieces.partition_point(|piece| piece.source.end <= value);
+        match self.pieces.get(i) {
+            Some(piece) if piece.source.start <= value => (piece.source.clone(), piece.dest_start),
+            next => {
+                let start = if i == 0 { T::min_value() } else { self.pieces[i - 1].source.end };
+                let end = next.map_or(T::max_value(), |next| next.source.start);
+                (start..end, start)
+            }
+        }
+    }
+
+    /// Every piece, and the gaps between them mapping to themselves, covering every value below T::max_value()
+    fn segments(&self) -> impl Iterator<Item=(Range<T>, T)> + '_ {
+        let mut start = T::min_value();
+        let mut pieces = self.pieces.iter().peekable();
+        std::iter::from_fn(move || {
+            if start == T::max_value() { return None }
+            let segment = match pieces.next_if(|piece| piece.source.start == start) {
+                Some(piece) => (piece.source.clone(), piece.dest_start),
+                None => (start..pieces.peek().map_or(T::max_value(), |next| next.source.start), start),
+            };
+            start = segment.0.end;
+            Some(segment)
+        })
+    }
+
+    /// Drops pieces that map to themselves, and merges neighbouring pieces that continue each other
+    fn from_pieces(mut pieces: Vec<Piece<T>>) -> Self {
+        pieces.sort_by_key(|piece| piece.source.start);
+        let mut merged: Vec<Piece<T>> = Vec::with_capacity(pieces.len());
+        for piece in pieces.into_iter().filter(|piece| !piece.source.is_empty() && piece.source.start != piece.dest_start) {
+            match merged.last_mut() {
+                Some(last) if last.source.end == piece.source.start
+                    && last.dest_start + (last.source.end - last.source.start) == piece.dest_start => last.source.end = piece.source.end,
+                _ => merged.push(piece),
+            }
+        }
+        RangeMap { pieces: merged }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InsertError<T> {
+    /// existing was already mapped, and source overlaps it
+    Overlap { source: Range<T>, existing: Range<T> },
+    /// The destination range would end beyond T::max_value()
+    DestinationOverflow { source: Range<T>, dest_start: T },
+}
+
+impl<T: Debug> Display for InsertError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsertError::Overlap { source, existing } => write!(f, "Source range {source:?} overlaps {existing:?}"),
+            InsertError::DestinationOverflow { source, dest_start } =>
+                write!(f, "Destination of source range {source:?} starting at {dest_start:?} overflows"),
+        }
+    }
+}
+
+impl<T: Debug> std::error::Error for InsertError<T> {}
+
+/// (source, destination start) pairs, which mustn't overlap or overflow
+impl<T: PrimInt + Debug> FromIterator<(Range<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item=(Range<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, dest_start) in iter {
+            map.insert(source, dest_start);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    use super::*;
+
+    /// Swaps 10..15 with 20..25
+    fn swap() -> RangeMap<u32> {
+        [(10..15, 20), (20..25, 10)].into_iter().collect()
+    }
+
+    #[test]
+    fn looks_up_pieces_and_gaps() {
+        let map = swap();
+        assert_eq!([0, 10, 14, 15, 22, 25, u32::MAX].map(|n| map.get(n)), [0, 20, 24, 15, 12, 25, u32::MAX]);
+    }
+
+    #[test]
+    fn overlapping_sources_are_rejected() {
+        let mut map = swap();
+        let error = map.try_insert(0..11, 50).unwrap_err();
+        assert_eq!(error, InsertError::Overlap { source: 0..11, existing: 10..15 });
+        assert_eq!(map, swap());
+        assert!(map.try_insert(0..10, 50).is_ok());
+    }
+
+    #[test]
+    fn overflowing_destinations_are_rejected() {
+        let mut map: RangeMap<u8> = RangeMap::new();
+        assert_eq!(map.try_insert(0..10, 250), Err(InsertError::DestinationOverflow { source: 0..10, dest_start: 250 }));
+        assert_eq!(map, RangeMap::new());
+        // the destination can end at u8::MAX, as sources can
+        assert!(map.try_insert(0..10, 245).is_ok());
+        assert_eq!(map.get(9), 254);
+    }
+
+    #[test]
+    fn composes_maps() {
+        let shift: RangeMap<u32> = [(0..30, 5)].into_iter().collect();
+        let composed = swap().then(&shift);
+        assert!((0..40).all(|n| composed.get(n) == shift.get(swap().get(n))));
+        // the swap undoes itself, leaving nothing to look up
+        assert_eq!(swap().then(&swap()), RangeMap::new());
+    }
+
+    #[test]
+    fn inverts_bijections() {
+        let map: RangeMap<u8> = [(0..10, 245), (245..255, 0)].into_iter().collect();
+        let inverse = map.invert().unwrap();
+        assert!((0..=255).all(|n| inverse.get(map.get(n)) == n && map.get(inverse.get(n)) == n));
+        assert_eq!(map.then(&inverse), RangeMap::new());
+    }
+
+    #[test]
+    fn no_inverse_of_non_bijections() {
+        // 20..25 would come from both 10..15 and itself
+        let map: RangeMap<u32> = [(10..15, 20)].into_iter().collect();
+        assert!(map.invert().is_none());
+    }
+
+    #[test]
+    fn random_permutations_round_trip() {
+        let mut rng = StdRng::seed_from_u64(19);
+        for _ in 0..50 {
+            // cut 0..60 into blocks and shuffle them, leaving everything from 60 in place
+            let mut cuts: Vec<u32> = (0..5).map(|_| rng.gen_range(1..60)).collect();
+            cuts.extend([0, 60]);
+            cuts.sort();
+            cuts.dedup();
+            let mut blocks: Vec<Range<u32>> = cuts.windows(2).map(|pair| pair[0]..pair[1]).collect();
+            blocks.shuffle(&mut rng);
+            let mut dest_start = 0;
+            let map: RangeMap<u32> = blocks.into_iter().map(|block| {
+                let piece = (block.clone(), dest_start);
+                dest_start += block.end - block.start;
+                piece
+            }).collect();
+
+            let inverse = map.invert().unwrap();
+            assert!((0..70).all(|n| inverse.get(map.get(n)) == n));
+            assert_eq!(map.then(&inverse), RangeMap::new());
+            assert_eq!(inverse.then(&map), RangeMap::new());
+            let values: IntervalSet<u32> = [(5..17), (40..65)].into_iter().collect();
+            assert_eq!(inverse.map_intervals(&map.map_intervals(&values)), values);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::AocError, input::InputSource};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Part { One, Two }
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(AocError::invalid(s, "Unknown part, expected 1 or 2"))
+        }
+    }
+}
+
+/// One day's puzzle. The input is parsed once, and then shared by both parts,
+/// so anything a part needs to mutate should be cloned out of the input first.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Type-erased entry point for a day, so that every day can be kept in the same registry.
+pub type Solver = fn(&InputSource, Part) -> Result<String, AocError>;
+
+pub fn solve<S: Solution>(source: &InputSource, part: Part) -> Result<String, AocError> {
+    let input = source.lines().map_err(AocError::from)
+        .and_then(S::parse)
+        .map_err(|e| e.with_day(S::DAY))?;
+    let answer = match part {
+        Part::One => S::part1(&input).to_string(),
+        Part::Two => S::part2(&input).to_string(),
+    };
+    Ok(answer)
+}
@@ -0,0 +1,205 @@
+use std::{collections::{BTreeMap, HashMap}, fmt::Display};
+
+use crate::{grid::{Grid, GridCell}, point::Point};
+
+/// Cells at a few points of an unbounded plane, e.g. the galaxies in 2023 day 11.
+/// Keeps track of the bounding box of the cells as they're added and removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    /// Number of cells in each row and column that has any, so the bounds are the first and last of each
+    rows: BTreeMap<i64, usize>,
+    columns: BTreeMap<i64, usize>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid { cells: HashMap::new(), rows: BTreeMap::new(), columns: BTreeMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        self.cells.get(p)
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.cells.get_mut(p)
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.cells.contains_key(p)
+    }
+
+    /// Returns the cell that was at p, if any
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        let previous = self.cells.insert(p, value);
+        if previous.is_none() {
+            *self.rows.entry(p.y).or_default() += 1;
+            *self.columns.entry(p.x).or_default() += 1;
+        }
+        previous
+    }
+
+    pub fn remove(&mut self, p: &Point) -> Option<T> {
+        let removed = self.cells.remove(p)?;
+        for (counts, i) in [(&mut self.rows, p.y), (&mut self.columns, p.x)] {
+            let count = counts.get_mut(&i).unwrap();
+            *count -= 1;
+            if *count == 0 { counts.remove(&i); }
+        }
+        Some(removed)
+    }
+
+    /// Top left corner of the bounding box, None if there are no cells
+    pub fn min(&self) -> Option<Point> {
+        Some(Point { x: *self.columns.first_key_value()?.0, y: *self.rows.first_key_value()?.0 })
+    }
+
+    /// Bottom right corner of the bounding box, which is included in it
+    pub fn max(&self) -> Option<Point> {
+        Some(Point { x: *self.columns.last_key_value()?.0, y: *self.rows.last_key_value()?.0 })
+    }
+
+    /// Number of cells in row y
+    pub fn row_len(&self, y: i64) -> usize {
+        self.rows.get(&y).copied().unwrap_or(0)
+    }
+
+    /// Number of cells in column x
+    pub fn column_len(&self, x: i64) -> usize {
+        self.columns.get(&x).copied().unwrap_or(0)
+    }
+
+    /// Points with cells, in reading order
+    pub fn points(&self) -> impl Iterator<Item=Point> + '_ {
+        self.iter().map(|(p, _)| p)
+    }
+
+    /// Cells in reading order
+    pub fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        let mut cells: Vec<(Point, &T)> = self.cells.iter().map(|(p, value)| (*p, value)).collect();
+        cells.sort_unstable_by_key(|(p, _)| (p.y, p.x));
+        cells.into_iter()
+    }
+
+    /// Every point of the bounding box, with the cell there if there is one.
+    /// The grid's (0, 0) is min(). Panics if there are no cells.
+    pub fn to_grid(&self) -> Grid<Option<T>> where T: Clone {
+        let (min, max) = (self.min().expect("No cells"), self.max().unwrap());
+        (min.y..=max.y)
+            .map(|y| (min.x..=max.x).map(|x| self.get(&Point { x, y }).cloned()).collect())
+            .collect()
+    }
+}
+
+/// Empty cells of the grid are left out, and the rest keep their points
+impl<T> From<Grid<Option<T>>> for SparseGrid<T> {
+    fn from(grid: Grid<Option<T>>) -> Self {
+        grid.into_enumerate().filter_map(|(p, cell)| cell.map(|value| (p, value))).collect()
+    }
+}
+
+/// Later cells at the same point replace earlier ones
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (p, value) in iter {
+            grid.insert(p, value);
+        }
+        grid
+    }
+}
+
+/// The bounding box, with . for points without a cell
+impl<T: GridCell> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else { return Ok(()) };
+        for y in min.y..=max.y {
+            if y > min.y { writeln!(f)? }
+            for x in min.x..=max.x {
+                write!(f, "{}", self.get(&Point { x, y }).map_or('.', GridCell::to_char))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> SparseGrid<char> {
+        [(Point { x: 3, y: -1 }, 'a'), (Point { x: -2, y: 2 }, 'b'), (Point { x: 0, y: 2 }, 'c'), (Point { x: 3, y: 4 }, 'd')]
+            .into_iter().collect()
+    }
+
+    #[test]
+    fn bounds_follow_cells() {
+        let mut grid = example();
+        assert_eq!((grid.min(), grid.max()), (Some(Point { x: -2, y: -1 }), Some(Point { x: 3, y: 4 })));
+        assert_eq!(grid.remove(&Point { x: 3, y: 4 }), Some('d'));
+        assert_eq!(grid.max(), Some(Point { x: 3, y: 2 }));
+        assert_eq!(grid.remove(&Point { x: -2, y: 2 }), Some('b'));
+        assert_eq!(grid.min(), Some(Point { x: 0, y: -1 }));
+        // nothing to remove, so the bounds stay the same
+        assert_eq!(grid.remove(&Point { x: -2, y: 2 }), None);
+        assert_eq!(grid.min(), Some(Point { x: 0, y: -1 }));
+        for p in [Point { x: 3, y: -1 }, Point { x: 0, y: 2 }] {
+            grid.remove(&p);
+        }
+        assert!(grid.is_empty());
+        assert_eq!((grid.min(), grid.max()), (None, None));
+    }
+
+    #[test]
+    fn replacing_a_cell_keeps_counts() {
+        let mut grid = example();
+        assert_eq!(grid.insert(Point { x: 0, y: 2 }, 'e'), Some('c'));
+        assert_eq!((grid.len(), grid.row_len(2), grid.column_len(0)), (4, 2, 1));
+        grid.remove(&Point { x: 0, y: 2 });
+        assert_eq!((grid.row_len(2), grid.column_len(0)), (1, 0));
+    }
+
+    #[test]
+    fn reading_order() {
+        let values: String = example().iter().map(|(_, c)| *c).collect();
+        assert_eq!(values, "abcd");
+        assert_eq!(example().points().next(), Some(Point { x: 3, y: -1 }));
+    }
+
+    #[test]
+    fn renders_bounding_box() {
+        assert_eq!(example().to_string(), "\
+.....a
+......
+......
+b.c...
+......
+.....d");
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    }
+
+    #[test]
+    fn converts_to_and_from_grid() {
+        let grid = example().to_grid();
+        assert_eq!((grid.width, grid.height), (6, 6));
+        assert_eq!(grid[&Point { x: 2, y: 3 }], Some('c'));
+        // the grid's points start from 0, so the cells move with them
+        let moved = SparseGrid::from(grid);
+        assert_eq!(moved.min(), Some(Point { x: 0, y: 0 }));
+        assert_eq!(moved.to_string(), example().to_string());
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Index;
+
+use crate::{direction::Direction, grid::Grid, point::Point};
+
+/// A grid repeated infinitely in every direction, so every point, including negative ones, is in
+/// some copy of it. e.g. the garden in 2023 day 21.
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Clone for TiledGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TiledGrid<'_, T> {}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// Panics if the grid has no cells, as there would be nothing to repeat
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(grid.width > 0 && grid.height > 0, "Can't tile an empty grid");
+        TiledGrid { grid }
+    }
+
+    /// The grid being repeated
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// The point in the original grid that p is a copy of
+    pub fn wrap(&self, p: &Point) -> Point {
+        Point { x: p.x.rem_euclid(self.grid.width), y: p.y.rem_euclid(self.grid.height) }
+    }
+
+    /// Which copy p is in, counted along each axis from the original grid at (0, 0)
+    pub fn tile(&self, p: &Point) -> Point {
+        Point { x: p.x.div_euclid(self.grid.width), y: p.y.div_euclid(self.grid.height) }
+    }
+
+    pub fn get(&self, p: &Point) -> &'a T {
+        &self.grid[&self.wrap(p)]
+    }
+
+    /// The points directly above, below, left and right of p, which are never out of bounds
+    pub fn neighbours(&self, p: &Point) -> impl Iterator<Item=Point> {
+        let p = *p;
+        Direction::all().into_iter().map(move |dir| p + dir)
+    }
+}
+
+impl<T> Index<&Point> for TiledGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        "ab.\n.b.".parse().unwrap()
+    }
+
+    #[test]
+    fn wraps_negative_points() {
+        let grid = example();
+        let tiled = TiledGrid::new(&grid);
+        assert_eq!(tiled.wrap(&Point { x: -1, y: -1 }), Point { x: 2, y: 1 });
+        assert_eq!(tiled.tile(&Point { x: -1, y: -1 }), Point { x: -1, y: -1 });
+        assert_eq!(tiled.wrap(&Point { x: -3, y: 1 }), Point { x: 0, y: 1 });
+        assert_eq!(tiled.tile(&Point { x: -3, y: 1 }), Point { x: -1, y: 0 });
+        assert_eq!(tiled.tile(&Point { x: -4, y: 2 }), Point { x: -2, y: 1 });
+        assert_eq!(tiled.tile(&Point { x: 2, y: 1 }), Point { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn indexes_match_grid() {
+        let grid = example();
+        let tiled = TiledGrid::new(&grid);
+        for (p, c) in grid.enumerate() {
+            for tile in [Point { x: 0, y: 0 }, Point { x: -1, y: 0 }, Point { x: 0, y: -1 }, Point { x: 2, y: -3 }] {
+                let copy = Point { x: p.x + tile.x * grid.width, y: p.y + tile.y * grid.height };
+                assert_eq!(tiled[&copy], *c);
+                assert_eq!((tiled.wrap(&copy), tiled.tile(&copy)), (p, tile));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't tile an empty grid")]
+    fn empty_grid() {
+        let grid: Grid<char> = Grid::new(vec![vec![]]);
+        TiledGrid::new(&grid);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+/// Disjoint sets of the elements 0..len, with path compression and union by size,
+/// so any sequence of operations takes effectively constant time each.
+#[derive(Clone, Debug, Default)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Each element starts in a set of its own
+    pub fn new(len: usize) -> Self {
+        UnionFind { parents: (0..len).collect(), sizes: vec![1; len], count: len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a new element in a set of its own
+    pub fn add(&mut self) -> usize {
+        let element = self.parents.len();
+        self.parents.push(element);
+        self.sizes.push(1);
+        self.count += 1;
+        element
+    }
+
+    /// Representative of the set containing element, the same for every element of the set
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // point everything on the way directly at the root
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing a and b, false if they were already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b { return false }
+        // attach the smaller tree under the larger, so trees stay shallow
+        let (larger, smaller) = if self.sizes[a] >= self.sizes[b] { (a, b) } else { (b, a) };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set containing element
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Each set's elements in increasing order, the sets ordered by their smallest element
+    pub fn components(&mut self) -> impl Iterator<Item=Vec<usize>> {
+        let mut indices: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *indices.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(element);
+        }
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_merge_sets() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 3));
+        assert!(sets.union(3, 5));
+        assert!(sets.union(1, 2));
+        assert!(!sets.union(5, 0));
+        assert_eq!(sets.count(), 3);
+        assert!(sets.same(0, 5));
+        assert!(!sets.same(0, 1));
+        assert_eq!(sets.size(3), 3);
+        assert_eq!(sets.size(4), 1);
+        assert_eq!(sets.components().collect::<Vec<_>>(), [vec![0, 3, 5], vec![1, 2], vec![4]]);
+    }
+
+    #[test]
+    fn added_elements_start_alone() {
+        let mut sets = UnionFind::default();
+        assert!(sets.is_empty());
+        let a = sets.add();
+        let b = sets.add();
+        assert_eq!((a, b), (0, 1));
+        assert_eq!(sets.count(), 2);
+        sets.union(a, b);
+        assert_eq!((sets.len(), sets.count(), sets.size(b)), (2, 1, 2));
+    }
+
+    #[test]
+    fn long_chains_compress() {
+        let mut sets = UnionFind::new(1000);
+        for i in 1..1000 {
+            sets.union(i - 1, i);
+        }
+        let root = sets.find(999);
+        assert!((0..1000).all(|i| sets.find(i) == root));
+        assert_eq!(sets.components().count(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, fmt::Display, fs, panic, path::PathBuf, time::{Duration, Instant}};
+
+use crate::{days, error::AocError, input::{self, InputSource}, solution::{Part, Solver}};
+
+/// Recorded answers for a day's input live alongside it, one "part: answer" line per part e.g.
+/// 1: 635
+/// 2: 734
+pub fn answers_path(day: u32) -> PathBuf {
+    input::input_dir().join(format!("day{day}.answers"))
+}
+
+/// Missing answers file is treated the same as having no answers recorded
+pub fn read_answers(day: u32) -> HashMap<Part, String> {
+    let path = answers_path(day);
+    let Ok(contents) = fs::read_to_string(&path) else { return HashMap::new() };
+    parse_answers(&contents).unwrap_or_else(|e| panic!("Invalid answers in {}: {e}", path.display()))
+}
+
+/// The contents of an answers file, ignoring blank lines
+pub fn parse_answers(contents: &str) -> Result<HashMap<Part, String>, AocError> {
+    contents.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let (part, answer) = line.split_once(':').ok_or_else(|| AocError::new("Expected 'part: answer'").at_line(i, line))?;
+            let part = part.trim().parse().map_err(|e: AocError| e.at_line(i, line))?;
+            Ok((part, String::from(answer.trim())))
+        })
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Mismatch { actual: String, expected: String },
+    /// Input couldn't be read, or the solver panicked (with the panic message if there was one)
+    Fail(String),
+    /// Solver ran, but there's no recorded answer to compare against
+    Unverified(String),
+}
+
+pub struct Verification {
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "PASS")?,
+            Outcome::Mismatch { actual, expected } => write!(f, "MISMATCH got {actual}, expected {expected}")?,
+            Outcome::Fail(message) => write!(f, "FAIL {message}")?,
+            Outcome::Unverified(actual) => write!(f, "UNVERIFIED got {actual}, no recorded answer")?,
+        }
+        write!(f, " ({}ms)", self.elapsed.as_millis())
+    }
+}
+
+/// Runs the day's solver for the given part, catching any panic so that one broken day
+/// doesn't stop the rest being verified.
+pub fn verify(day: u32, part: Part, answers: &HashMap<Part, String>) -> Verification {
+    let solver = days::get_solver(day).unwrap_or_else(|| panic!("No solution for day {day}"));
+    verify_with(day, part, solver, &InputSource::Day(day), answers)
+}
+
+/// As verify, with any solver and input e.g. one held in memory
+pub fn verify_with(day: u32, part: Part, solver: Solver, source: &InputSource, answers: &HashMap<Part, String>) -> Verification {
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| solver(source, part));
+    let elapsed = start.elapsed();
+
+    let outcome = match (result, answers.get(&part)) {
+        (Err(payload), _) => Outcome::Fail(panic_message(payload)),
+        (Ok(Err(error)), _) => Outcome::Fail(error.to_string()),
+        (Ok(Ok(actual)), None) => Outcome::Unverified(actual),
+        (Ok(Ok(actual)), Some(expected)) if actual == *expected => Outcome::Pass,
+        (Ok(Ok(actual)), Some(expected)) => Outcome::Mismatch { actual, expected: expected.clone() },
+    };
+    Verification { day, part, outcome, elapsed }
+}
+
+pub fn verify_day(day: u32, parts: &[Part]) -> Vec<Verification> {
+    let answers = read_answers(day);
+    parts.iter().map(|part| verify(day, *part, &answers)).collect()
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{days::day6::Day6, solution::solve};
+
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    fn answers(contents: &str) -> HashMap<Part, String> {
+        parse_answers(contents).unwrap()
+    }
+
+    fn outcome(part: Part, solver: Solver, answers: &HashMap<Part, String>) -> Outcome {
+        verify_with(6, part, solver, &InputSource::from(EXAMPLE), answers).outcome
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = answers("1: 288\n\n 2 :71503 \n");
+        assert_eq!(answers.get(&Part::One).map(String::as_str), Some("288"));
+        assert_eq!(answers.get(&Part::Two).map(String::as_str), Some("71503"));
+        assert!(parse_answers("").unwrap().is_empty());
+
+        assert_eq!(parse_answers("1: 288\n2 71503").err().unwrap().to_string(), "line 2: Expected 'part: answer' \"2 71503\"");
+        assert_eq!(parse_answers("3: 288").err().unwrap().to_string(), "line 1, column 1: Unknown part, expected 1 or 2 \"3\"");
+    }
+
+    #[test]
+    fn reports_pass_mismatch_and_unverified() {
+        let answers = answers("1: 288\n2: 1");
+        assert_eq!(outcome(Part::One, solve::<Day6>, &answers), Outcome::Pass);
+        assert_eq!(outcome(Part::Two, solve::<Day6>, &answers),
+            Outcome::Mismatch { actual: String::from("71503"), expected: String::from("1") });
+        assert_eq!(outcome(Part::Two, solve::<Day6>, &HashMap::new()), Outcome::Unverified(String::from("71503")));
+
+        let verification = verify_with(6, Part::Two, solve::<Day6>, &InputSource::from(EXAMPLE), &answers);
+        assert!(verification.to_string().starts_with("Day 6 part 2: MISMATCH got 71503, expected 1 ("));
+    }
+
+    #[test]
+    fn reports_failures() {
+        let error = verify_with(6, Part::One, solve::<Day6>, &InputSource::from("Time: 7"), &HashMap::new());
+        assert_eq!(error.outcome, Outcome::Fail(String::from("Day 6: Expected time and distance lines")));
+
+        fn panics(_: &InputSource, part: Part) -> Result<String, AocError> {
+            panic!("Part {part} panicked")
+        }
+        assert_eq!(outcome(Part::Two, panics, &HashMap::new()), Outcome::Fail(String::from("Part 2 panicked")));
+        fn panics_without_message(_: &InputSource, _: Part) -> Result<String, AocError> {
+            std::panic::panic_any(0)
+        }
+        assert_eq!(outcome(Part::One, panics_without_message, &HashMap::new()), Outcome::Fail(String::from("unknown panic")));
+    }
+}